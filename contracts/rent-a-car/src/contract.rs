@@ -1,21 +1,98 @@
-use soroban_sdk::{Address, Env, contract, contractimpl};
+// `rental` and the client and invoke wrappers generated for it take more
+// arguments than clippy allows
+#![allow(clippy::too_many_arguments)]
+
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec, contract, contractimpl};
 use crate::{interfaces::contract::RentACarContractTrait, 
     storage::{
         admin::{read_admin, write_admin, has_admin},
         car::{read_car, write_car, remove_car, has_car},
         token::write_token,
-        types::{car_status::CarStatus, errors::Error},
-        structs::{car::Car, rental::Rental},
-        rental::{write_rental, has_rental, remove_rental},
+        car_token::{
+            has_car_token, read_car_token_balance, read_car_token_holder, read_car_token_listing, write_car_token,
+        },
+        types::{
+            car_status::CarStatus, errors::Error, pricing_rule::PricingRule, promo_discount::PromoDiscount,
+            payment_source::PaymentSource, rental_unit::RentalUnit,
+        },
+        structs::{
+            car::Car, rental::Rental, quote::Quote, reconcile_report::ReconcileReport, reputation::Reputation,
+            promo_code::PromoCode, corporate_account::CorporateAccount,
+            insurance::{Coverage, InsurancePlan, InsurancePool}, tax_config::TaxConfig, revenue_share::RevenueShare,
+        },
+        rental::{write_rental, read_rental, has_rental, remove_rental},
         contract_balance::{read_contract_balance, write_contract_balance},
         admin_fee::{read_admin_fee, write_admin_fee},
         admin_fees_balance::{read_admin_fees_balance, write_admin_fees_balance},
+        payout_address::{read_payout_address, write_payout_address, remove_payout_address},
+        reputation::{read_reputation, read_rating_status, write_rating_status},
+        pricing_rules::{read_pricing_rules, remove_pricing_rules, write_pricing_rules},
+        corporate::{
+            has_corporate_account, is_corporate_driver, read_corporate_account, write_corporate_account,
+            write_corporate_driver, read_corporate_balances, write_corporate_balances,
+        },
+        credit::read_credit,
+        sponsor::{is_sponsored_renter, write_sponsored_renter},
+        revenue_share::{
+            read_revenue_shares, read_share_balance, read_share_balances, remove_revenue_shares,
+            write_revenue_shares, write_share_balance, write_share_balances,
+        },
+        tax::{read_tax_balance, read_tax_config, read_tax_rate, write_tax_balance, write_tax_config},
+        insurance::{read_coverage, read_insurance_plan, read_insurance_pool, remove_insurance_plan, write_insurance_plan},
+        promo_code::{has_promo_code, read_promo_code, remove_promo_code, write_promo_code},
+        blocklist::{is_blocked_by_owner, is_blocked_globally, write_blocked_by_owner, write_blocked_globally},
+        verification::{
+            read_attestor, write_attestor, remove_attestor,
+            read_eligibility_verifier, write_eligibility_verifier, remove_eligibility_verifier,
+            read_renter_verification, write_renter_verification, remove_renter_verification,
+        },
     },
     methods::{
+        accounting::{build_reconcile_report, sweep_surplus},
+        eligibility::{
+            check_renter_eligible, check_renter_not_blocked, check_renter_score, check_renter_verified,
+            require_verifier,
+        },
+        reputation::{add_rating, record_completed_rental, record_dispute_lost, MAX_RATING},
         token::token::token_transfer,
+        payout::pay_owner,
+        car_token::{
+            burn_car_token, car_holder, car_payout_address, mint_car_token, move_car_token, require_car_holder,
+        },
+        pricing::{compute_quote, validate_pricing_rules, validate_rental_limits, validate_tax_rate},
+        promo::{redeem_promo_code, validate_promo_code},
+        payment::{add_credit, collect_payment, use_credit},
+        insurance::{buy_coverage, pay_claim},
+        revenue::{split_settled, validate_revenue_shares},
+        settlement::settle_accrued,
         public,
     },
-    events,
+    events::{
+        self,
+        add_car::CarAddedEvent,
+        blocklist::RenterBlocklistEvent,
+        rental_limits::RentalLimitsSetEvent,
+        pricing::{HourlyPriceSetEvent, PricingRulesSetEvent},
+        credit::CreditEvent,
+        sponsor::SponsoredRenterSetEvent,
+        insurance::{CoveragePurchasedEvent, InsuranceClaimPaidEvent, InsurancePlanSetEvent},
+        tax::{TaxConfigSetEvent, TaxRemittedEvent},
+        revenue::{RevenueSharesSetEvent, ShareWithdrawnEvent},
+        corporate::{CorporateBalanceEvent, CorporateDriverSetEvent, CorporateLimitSetEvent},
+        promo::{PromoCodeCreatedEvent, PromoCodeDisabledEvent, PromoRedeemedEvent},
+        contract::{AdminFeeSetEvent, ContractInitializedEvent, SurplusSweptEvent},
+        payout_owner::{AdminFeesWithdrawnEvent, BatchPayoutEvent, PayoutAddressSetEvent},
+        remove_car::CarRemovedEvent,
+        transfer_car::CarTransferredEvent,
+        car_token::CarTokenTransferredEvent,
+        rental::RentedEvent,
+        return_car::CarReturnedEvent,
+        reputation::{MinRenterScoreSetEvent, RatingSubmittedEvent},
+        settle::RentalSettledEvent,
+        verification::{
+            AttestorSetEvent, EligibilityVerifierSetEvent, RenterVerifiedEvent, VerifiedRentersOnlySetEvent,
+        },
+    },
 };
 
 
@@ -35,7 +112,7 @@ impl RentACarContractTrait for RentACarContract {
         }
         write_admin(env, &admin);
         write_token(env, &token);
-        events::contract::contract_initialized(env, admin, token);
+        events::contract::contract_initialized(env, ContractInitializedEvent { admin, token });
         Ok(())
     }
 
@@ -43,7 +120,14 @@ impl RentACarContractTrait for RentACarContract {
         read_admin(env)
     }
 
-    fn add_car(env: &Env, owner: Address, price_per_day: i128, commission_amount: i128) -> Result<(), Error> {
+    fn add_car(
+        env: &Env,
+        owner: Address,
+        price_per_day: i128,
+        commission_amount: i128,
+        min_days: u32,
+        max_days: u32,
+    ) -> Result<(), Error> {
         let admin = read_admin(env);
         admin.require_auth();
         if price_per_day <= 0 {
@@ -55,18 +139,29 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::InvalidCommissionAmount);
         }
 
+        validate_rental_limits(min_days, max_days)?;
+
         if has_car(env, &owner) {
             return Err(Error::CarAlreadyExist);
         }
+        let token_id = mint_car_token(env, &owner);
         let car = Car {
+            token_id,
             price_per_day,
+            price_per_hour: 0,
             car_status: CarStatus::Available,
             available_to_withdraw: 0,
+            pending_to_withdraw: 0,
             commission_amount,
+            current_renter: None,
+            verified_renters_only: false,
+            min_renter_score: 0,
+            min_days,
+            max_days,
         };
 
         write_car(env, &owner, &car);
-        events::add_car::car_added(env, owner, price_per_day);
+        events::add_car::car_added(env, CarAddedEvent { owner, token_id, price_per_day, commission_amount });
         Ok(())
     }
 
@@ -78,18 +173,36 @@ impl RentACarContractTrait for RentACarContract {
         public::get_car_info(env, &owner)
     }
 
+    fn get_car_balances(env: &Env, owner: Address) -> Result<(i128, i128), Error> {
+        public::get_car_balances(env, &owner)
+    }
+
     fn has_rental(env: &Env, renter: Address, owner: Address) -> bool {
         public::check_has_rental(env, &renter, &owner)
     }
 
-    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128) -> Result<(), Error> {
+    fn quote_rental(env: &Env, owner: Address, unit: RentalUnit, units: u32, start: u64) -> Result<Quote, Error> {
+        public::quote_rental(env, &owner, unit, units, start)
+    }
+
+    fn rental(
+        env: &Env,
+        renter: Address,
+        owner: Address,
+        unit: RentalUnit,
+        units: u32,
+        amount: i128,
+        promo_code: Option<Bytes>,
+        payment: PaymentSource,
+        insured: bool,
+    ) -> Result<(), Error> {
         renter.require_auth();
 
         if amount <= 0 {
             return Err(Error::AmountMustBePositive);
         }
 
-        if total_days_to_rent == 0 {
+        if units == 0 {
             return Err(Error::RentalDurationCannotBeZero);
         }
 
@@ -101,49 +214,134 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::CarNotFound);
         }
 
+        // The listing may have changed hands since it was added
+        if renter == car_holder(env, &owner) {
+            return Err(Error::SelfRentalNotAllowed);
+        }
+
         let mut car = read_car(env, &owner);
 
-        if car.car_status != CarStatus::Available {
-            return Err(Error::CarAlreadyRented);
+        match car.car_status {
+            CarStatus::Available => {}
+            CarStatus::Rented => return Err(Error::CarAlreadyRented),
+            CarStatus::Maintenance => return Err(Error::CarInMaintenance),
         }
 
-        // Use fixed commission amount from car
-        let admin_fee = car.commission_amount;
-        let total_amount = amount
-            .checked_add(admin_fee)
+        check_renter_not_blocked(env, &owner, &renter)?;
+        if car.verified_renters_only {
+            check_renter_verified(env, &renter)?;
+        }
+        check_renter_score(env, &renter, car.min_renter_score)?;
+        check_renter_eligible(env, &renter, &owner)?;
+
+        // Price the rental with the same code path as `quote_rental`
+        let mut quote = compute_quote(
+            &car,
+            &read_pricing_rules(env, &owner),
+            read_tax_rate(env),
+            unit,
+            units,
+            env.ledger().timestamp(),
+        )?;
+        // `amount` is the most rent the renter accepts: per-unit pricing depends
+        // on the ledger second, so the rent can shift between quote and rental
+        if quote.base_rent > amount {
+            return Err(Error::AmountDoesNotMatchQuote);
+        }
+
+        let redeemed_code = match promo_code {
+            Some(code) => Some(redeem_promo_code(env, &code, &renter, &mut quote)?),
+            None => None,
+        };
+
+        // Promo discounts come out of the commission, never out of the rent
+        let admin_fee = quote.commission
+            .checked_sub(quote.discount)
+            .ok_or(Error::Underflow)?;
+        let duration = quote.end - quote.start;
+
+        let coverage = if insured {
+            Some(buy_coverage(env, &renter, &owner, car.token_id, duration)?)
+        } else {
+            None
+        };
+        let premium = coverage.as_ref().map_or(0, |(_, coverage)| coverage.premium);
+        let total_amount = quote.total
+            .checked_add(premium)
             .ok_or(Error::Overflow)?;
 
         car.car_status = CarStatus::Rented;
-        // Only the rental amount (without fee) goes to the owner
-        car.available_to_withdraw = car.available_to_withdraw
-            .checked_add(amount)
+        let pending_before = car.pending_to_withdraw;
+        // Only the rental amount (without fee) goes to the owner,
+        // held as pending until the rental settles
+        car.pending_to_withdraw = car.pending_to_withdraw
+            .checked_add(quote.base_rent)
             .ok_or(Error::Overflow)?;
 
+        car.current_renter = Some(renter.clone());
+
         let rental = Rental {
-            total_days_to_rent,
-            amount,
+            unit,
+            units,
+            duration,
+            amount: quote.base_rent,
+            start_time: quote.start,
+            settled_amount: 0,
+            payment,
         };
 
-        // Update contract balance with total amount (deposit + fee)
-        let mut contract_balance = read_contract_balance(env);
-        contract_balance = contract_balance
-            .checked_add(total_amount)
-            .ok_or(Error::Overflow)?;
-
         // Update admin fees balance
         let mut admin_fees_balance = read_admin_fees_balance(env);
         admin_fees_balance = admin_fees_balance
             .checked_add(admin_fee)
             .ok_or(Error::Overflow)?;
 
-        write_contract_balance(env, &contract_balance);
         write_admin_fees_balance(env, &admin_fees_balance);
+
+        // Tax is held apart until it is remitted to the collector
+        let tax_balance = read_tax_balance(env)
+            .checked_add(quote.tax)
+            .ok_or(Error::Overflow)?;
+        write_tax_balance(env, &tax_balance);
         write_car(env, &owner, &car);
         write_rental(env, &renter, &owner, &rental);
 
-        // Transfer total amount (deposit + fee) from renter to contract
-        token_transfer(env, &renter, &env.current_contract_address(), &total_amount);
-        events::rental::rented(env, renter, owner, total_days_to_rent, total_amount);
+        // Collect total amount (rent + fee + tax + premium) from the renter or whoever pays for them
+        collect_payment(env, &renter, &rental.payment, total_amount)?;
+        if let Some((coverage_id, coverage)) = coverage {
+            events::insurance::coverage_purchased(env, CoveragePurchasedEvent {
+                coverage_id,
+                owner: owner.clone(),
+                renter: renter.clone(),
+                premium: coverage.premium,
+                coverage_cap: coverage.coverage_cap,
+                pool_after: read_insurance_pool(env).balance,
+            });
+        }
+        if let Some((code_hash, uses)) = redeemed_code {
+            events::promo::promo_redeemed(env, PromoRedeemedEvent {
+                owner: owner.clone(),
+                renter: renter.clone(),
+                code_hash,
+                discount: quote.discount,
+                uses,
+            });
+        }
+        events::rental::rented(env, RentedEvent {
+            owner,
+            payer: rental.payment.payer(&renter),
+            renter,
+            unit,
+            units,
+            start_time: quote.start,
+            end_time: quote.end,
+            base_rent: quote.base_rent,
+            commission: admin_fee,
+            tax: quote.tax,
+            total_amount,
+            pending_before,
+            pending_after: car.pending_to_withdraw,
+        });
         Ok(())
     }
 
@@ -163,71 +361,866 @@ impl RentACarContractTrait for RentACarContract {
 
         // Verify the car is currently rented
         if car.car_status != CarStatus::Rented {
-            return Err(Error::CarAlreadyRented);
+            return Err(Error::CarNotRented);
         }
 
-        // Change car status to Available
+        // Change car status to Available and settle the rental earnings
         car.car_status = CarStatus::Available;
+        let available_before = car.available_to_withdraw;
+        let settled_amount = car.pending_to_withdraw;
+        car.available_to_withdraw = car.available_to_withdraw
+            .checked_add(car.pending_to_withdraw)
+            .ok_or(Error::Overflow)?;
+        car.pending_to_withdraw = 0;
+        car.current_renter = None;
+        split_settled(env, &owner, &mut car, settled_amount)?;
+
+        let rental = read_rental(env, &renter, &owner);
+        let end_time = rental.start_time
+            .checked_add(rental.duration)
+            .ok_or(Error::Overflow)?;
+        let late = env.ledger().timestamp() > end_time;
 
         write_car(env, &owner, &car);
         remove_rental(env, &renter, &owner);
+        record_completed_rental(env, &renter, &owner, late)?;
 
-        events::return_car::car_returned(env, renter, owner);
+        events::return_car::car_returned(env, CarReturnedEvent {
+            owner,
+            renter,
+            returned_at: env.ledger().timestamp(),
+            settled_amount,
+            available_before,
+            available_after: car.available_to_withdraw,
+        });
         Ok(())
     }
 
+    fn settle(env: &Env, owner: Address) -> Result<i128, Error> {
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, &owner);
+        let renter = match car.current_renter.clone() {
+            Some(renter) if car.car_status == CarStatus::Rented => renter,
+            _ => return Err(Error::CarNotRented),
+        };
+        let mut rental = read_rental(env, &renter, &owner);
+        let settled_before = rental.settled_amount;
+
+        let settled = settle_accrued(&mut car, &mut rental, env.ledger().timestamp())?;
+        if settled > 0 {
+            split_settled(env, &owner, &mut car, settled)?;
+            write_car(env, &owner, &car);
+            write_rental(env, &renter, &owner, &rental);
+            events::settle::rental_settled(env, RentalSettledEvent {
+                owner,
+                renter,
+                amount: settled,
+                settled_before,
+                settled_after: rental.settled_amount,
+                available_after: car.available_to_withdraw,
+                pending_after: car.pending_to_withdraw,
+            });
+        }
+        Ok(settled)
+    }
+
     fn remove_car(env: &Env, owner: Address) -> Result<(), Error> {
+        require_car_holder(env, &owner)?;
 
-        let admin = read_admin(env);
-        admin.require_auth();
+        let car = read_car(env, &owner);
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+        // Rental escrow can't be left without a car to settle against
+        if car.car_status == CarStatus::Rented {
+            return Err(Error::CarNotReturned);
         }
-        
+
+        // Settled earnings are paid out so they don't get stranded
+        let paid_out = car.available_to_withdraw;
+        if paid_out > 0 {
+            pay_owner(env, &owner, paid_out)?;
+        }
+
+        burn_car_token(env, car.token_id);
         remove_car(env, &owner);
-        events::remove_car::car_removed(env, owner);
+        remove_pricing_rules(env, &owner);
+        remove_revenue_shares(env, &owner);
+        events::remove_car::car_removed(env, CarRemovedEvent {
+            owner,
+            token_id: car.token_id,
+            price_per_day: car.price_per_day,
+            commission_amount: car.commission_amount,
+            paid_out,
+        });
+        Ok(())
+    }
+
+    fn transfer_car(env: &Env, owner: Address, new_owner: Address) -> Result<(), Error> {
+        require_car_holder(env, &owner)?;
+        new_owner.require_auth();
+
+        if has_car(env, &new_owner) {
+            return Err(Error::CarAlreadyExist);
+        }
+
+        // A sale is a token transfer that also relists the car under the
+        // buyer's address. Earnings and co-owner shares are wound up with the
+        // token; the listing keeps its prices and booking rules.
+        let paid_out = move_car_token(env, &owner, &new_owner)?;
+        let car = read_car(env, &owner);
+        let rules = read_pricing_rules(env, &owner);
+        remove_car(env, &owner);
+        remove_pricing_rules(env, &owner);
+
+        write_car(env, &new_owner, &car);
+        write_pricing_rules(env, &new_owner, &rules);
+        write_car_token(env, car.token_id, &new_owner, &new_owner);
+
+        events::transfer_car::car_transferred(env, CarTransferredEvent {
+            owner,
+            new_owner,
+            token_id: car.token_id,
+            paid_out,
+        });
         Ok(())
     }
 
+    // Moves the listing's NFT without relisting the car: it stays under the
+    // same `owner` key, but the new holder now controls it
+    fn transfer(env: &Env, from: Address, to: Address, token_id: u32) -> Result<(), Error> {
+        from.require_auth();
+
+        if !has_car_token(env, token_id) {
+            return Err(Error::CarNotFound);
+        }
+        if read_car_token_holder(env, token_id) != from {
+            return Err(Error::Unauthorized);
+        }
+
+        let owner = read_car_token_listing(env, token_id);
+        let paid_out = move_car_token(env, &owner, &to)?;
+
+        events::car_token::car_token_transferred(env, CarTokenTransferredEvent { owner, token_id, from, to, paid_out });
+        Ok(())
+    }
+
+    fn balance(env: &Env, account: Address) -> u32 {
+        read_car_token_balance(env, &account)
+    }
+
+    fn owner_of(env: &Env, token_id: u32) -> Result<Address, Error> {
+        if !has_car_token(env, token_id) {
+            return Err(Error::CarNotFound);
+        }
+        Ok(read_car_token_holder(env, token_id))
+    }
+
+    fn get_car_token(env: &Env, owner: Address) -> Result<u32, Error> {
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        Ok(read_car(env, &owner).token_id)
+    }
+
     fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error> {
-        owner.require_auth();
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        require_car_holder(env, &owner)?;
+        pay_owner(env, &owner, amount)
+    }
+
+    fn payout_owner_all(env: &Env, owner: Address) -> Result<i128, Error> {
+        require_car_holder(env, &owner)?;
 
+        let amount = read_car(env, &owner).available_to_withdraw;
         if amount <= 0 {
+            return Err(Error::InsufficientBalance);
+        }
+
+        pay_owner(env, &owner, amount)?;
+        Ok(amount)
+    }
+
+    fn batch_payout(env: &Env, owners: Vec<Address>) -> Result<i128, Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        let mut owners_paid: u32 = 0;
+        let mut owners_skipped: u32 = 0;
+        let mut total_paid: i128 = 0;
+
+        // A delisted owner or an empty balance skips the owner instead of
+        // failing the whole batch
+        for owner in owners.iter() {
+            if !has_car(env, &owner) {
+                owners_skipped += 1;
+                continue;
+            }
+
+            let amount = read_car(env, &owner).available_to_withdraw;
+            if amount <= 0 {
+                owners_skipped += 1;
+                continue;
+            }
+
+            pay_owner(env, &owner, amount)?;
+            owners_paid += 1;
+            total_paid = total_paid
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+        }
+
+        events::payout_owner::batch_payout(env, BatchPayoutEvent {
+            admin,
+            owners_paid,
+            owners_skipped,
+            total_amount: total_paid,
+        });
+        Ok(total_paid)
+    }
+
+    fn set_payout_address(env: &Env, owner: Address, beneficiary: Address) -> Result<(), Error> {
+        let holder = require_car_holder(env, &owner)?;
+
+        let previous = car_payout_address(env, &owner);
+        if beneficiary == holder {
+            remove_payout_address(env, &owner);
+        } else {
+            write_payout_address(env, &owner, &beneficiary);
+        }
+
+        events::payout_owner::payout_address_set(env, PayoutAddressSetEvent {
+            owner,
+            previous,
+            beneficiary,
+        });
+        Ok(())
+    }
+
+    fn get_payout_address(env: &Env, owner: Address) -> Address {
+        // Co-owners without a car of their own are paid at their own address
+        if has_car(env, &owner) {
+            car_payout_address(env, &owner)
+        } else {
+            read_payout_address(env, &owner)
+        }
+    }
+
+    fn set_attestor(env: &Env, attestor: Option<Address>) -> Result<(), Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        let previous = read_attestor(env);
+        match &attestor {
+            Some(attestor) => write_attestor(env, attestor),
+            None => remove_attestor(env),
+        }
+
+        events::verification::attestor_set(env, AttestorSetEvent { admin, previous, attestor });
+        Ok(())
+    }
+
+    fn set_eligibility_verifier(env: &Env, verifier: Option<Address>) -> Result<(), Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        let previous = read_eligibility_verifier(env);
+        match &verifier {
+            Some(verifier) => write_eligibility_verifier(env, verifier),
+            None => remove_eligibility_verifier(env),
+        }
+
+        events::verification::eligibility_verifier_set(env, EligibilityVerifierSetEvent {
+            admin,
+            previous,
+            verifier,
+        });
+        Ok(())
+    }
+
+    fn get_eligibility_verifier(env: &Env) -> Option<Address> {
+        read_eligibility_verifier(env)
+    }
+
+    fn verify_renter(env: &Env, verifier: Address, renter: Address, expires_at: u64) -> Result<(), Error> {
+        require_verifier(env, &verifier)?;
+
+        if expires_at <= env.ledger().timestamp() {
+            return Err(Error::InvalidTimeRange);
+        }
+
+        let previous_expires_at = read_renter_verification(env, &renter);
+        write_renter_verification(env, &renter, &expires_at);
+
+        events::verification::renter_verification_set(env, RenterVerifiedEvent {
+            renter,
+            verifier,
+            previous_expires_at,
+            expires_at: Some(expires_at),
+        });
+        Ok(())
+    }
+
+    fn revoke_renter_verification(env: &Env, verifier: Address, renter: Address) -> Result<(), Error> {
+        require_verifier(env, &verifier)?;
+
+        let previous_expires_at = read_renter_verification(env, &renter);
+        remove_renter_verification(env, &renter);
+
+        events::verification::renter_verification_set(env, RenterVerifiedEvent {
+            renter,
+            verifier,
+            previous_expires_at,
+            expires_at: None,
+        });
+        Ok(())
+    }
+
+    fn get_renter_verification(env: &Env, renter: Address) -> Option<u64> {
+        read_renter_verification(env, &renter)
+    }
+
+    fn set_verified_renters_only(env: &Env, owner: Address, enabled: bool) -> Result<(), Error> {
+        require_car_holder(env, &owner)?;
+
+        let mut car = read_car(env, &owner);
+        car.verified_renters_only = enabled;
+        write_car(env, &owner, &car);
+
+        events::verification::verified_renters_only_set(env, VerifiedRentersOnlySetEvent { owner, enabled });
+        Ok(())
+    }
+
+    fn rate_renter(env: &Env, owner: Address, renter: Address, score: u32) -> Result<(), Error> {
+        owner.require_auth();
+
+        let mut status = read_rating_status(env, &renter, &owner).ok_or(Error::RatingNotAllowed)?;
+        if status.owner_rated {
+            return Err(Error::AlreadyRated);
+        }
+
+        let reputation = add_rating(env, &renter, score)?;
+        status.owner_rated = true;
+        write_rating_status(env, &renter, &owner, &status);
+
+        events::reputation::rating_submitted(env, RatingSubmittedEvent {
+            owner: owner.clone(),
+            renter: renter.clone(),
+            rater: owner,
+            rated: renter,
+            score,
+            rating_count: reputation.rating_count,
+            rating_sum: reputation.rating_sum,
+        });
+        Ok(())
+    }
+
+    fn rate_owner(env: &Env, renter: Address, owner: Address, score: u32) -> Result<(), Error> {
+        renter.require_auth();
+
+        let mut status = read_rating_status(env, &renter, &owner).ok_or(Error::RatingNotAllowed)?;
+        if status.renter_rated {
+            return Err(Error::AlreadyRated);
+        }
+
+        let reputation = add_rating(env, &owner, score)?;
+        status.renter_rated = true;
+        write_rating_status(env, &renter, &owner, &status);
+
+        events::reputation::rating_submitted(env, RatingSubmittedEvent {
+            owner: owner.clone(),
+            renter: renter.clone(),
+            rater: renter,
+            rated: owner,
+            score,
+            rating_count: reputation.rating_count,
+            rating_sum: reputation.rating_sum,
+        });
+        Ok(())
+    }
+
+    fn get_reputation(env: &Env, address: Address) -> Reputation {
+        read_reputation(env, &address)
+    }
+
+    fn set_hourly_price(env: &Env, owner: Address, price_per_hour: i128) -> Result<(), Error> {
+        require_car_holder(env, &owner)?;
+
+        // 0 stops offering hourly rentals
+        if price_per_hour < 0 {
             return Err(Error::AmountMustBePositive);
         }
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+        let mut car = read_car(env, &owner);
+        let previous = car.price_per_hour;
+        car.price_per_hour = price_per_hour;
+        write_car(env, &owner, &car);
+
+        events::pricing::hourly_price_set(env, HourlyPriceSetEvent { owner, previous, price_per_hour });
+        Ok(())
+    }
+
+    fn set_pricing_rules(env: &Env, owner: Address, rules: Vec<PricingRule>) -> Result<(), Error> {
+        require_car_holder(env, &owner)?;
+
+        // Replaces the whole rule set, an empty list goes back to flat pricing
+        validate_pricing_rules(&rules)?;
+        write_pricing_rules(env, &owner, &rules);
+
+        events::pricing::pricing_rules_set(env, PricingRulesSetEvent { owner, rules });
+        Ok(())
+    }
+
+    fn get_pricing_rules(env: &Env, owner: Address) -> Result<Vec<PricingRule>, Error> {
+        public::get_pricing_rules(env, &owner)
+    }
+
+    fn set_rental_limits(env: &Env, owner: Address, min_days: u32, max_days: u32) -> Result<(), Error> {
+        require_car_holder(env, &owner)?;
+
+        validate_rental_limits(min_days, max_days)?;
+
+        // Only applies to new bookings, a rental in progress keeps its length
+        let mut car = read_car(env, &owner);
+        car.min_days = min_days;
+        car.max_days = max_days;
+        write_car(env, &owner, &car);
+
+        events::rental_limits::rental_limits_set(env, RentalLimitsSetEvent { owner, min_days, max_days });
+        Ok(())
+    }
+
+    fn get_rental_limits(env: &Env, owner: Address) -> Result<(u32, u32), Error> {
+        public::get_rental_limits(env, &owner)
+    }
+
+    fn set_min_renter_score(env: &Env, owner: Address, min_renter_score: u32) -> Result<(), Error> {
+        require_car_holder(env, &owner)?;
+
+        // Scores are averages x100, so 500 is the 5 star maximum
+        if min_renter_score > MAX_RATING * 100 {
+            return Err(Error::InvalidRating);
         }
 
         let mut car = read_car(env, &owner);
+        let previous = car.min_renter_score;
+        car.min_renter_score = min_renter_score;
+        write_car(env, &owner, &car);
 
-        // Owners can only withdraw when car is returned (Available)
-        if car.car_status != CarStatus::Available {
-            return Err(Error::CarNotReturned);
+        events::reputation::min_renter_score_set(env, MinRenterScoreSetEvent {
+            owner,
+            previous,
+            min_renter_score,
+        });
+        Ok(())
+    }
+
+    fn block_renter(env: &Env, owner: Address, renter: Address) -> Result<(), Error> {
+        set_owner_block(env, owner, renter, true)
+    }
+
+    fn unblock_renter(env: &Env, owner: Address, renter: Address) -> Result<(), Error> {
+        set_owner_block(env, owner, renter, false)
+    }
+
+    fn block_renter_globally(env: &Env, renter: Address) -> Result<(), Error> {
+        set_global_block(env, renter, true)
+    }
+
+    fn unblock_renter_globally(env: &Env, renter: Address) -> Result<(), Error> {
+        set_global_block(env, renter, false)
+    }
+
+    fn is_renter_blocked(env: &Env, owner: Address, renter: Address) -> bool {
+        is_blocked_globally(env, &renter) || is_blocked_by_owner(env, &owner, &renter)
+    }
+
+    fn create_promo_code(
+        env: &Env,
+        code_hash: BytesN<32>,
+        discount: PromoDiscount,
+        max_uses: u32,
+        expires_at: u64,
+        per_renter_limit: Option<u32>,
+    ) -> Result<(), Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        // Only the sha256 of the code is stored, renters reveal it in `rental`
+        if has_promo_code(env, &code_hash) {
+            return Err(Error::PromoCodeAlreadyExists);
+        }
+
+        let promo = PromoCode { discount: discount.clone(), max_uses, uses: 0, expires_at, per_renter_limit };
+        validate_promo_code(env, &promo)?;
+        write_promo_code(env, &code_hash, &promo);
+
+        events::promo::promo_code_created(env, PromoCodeCreatedEvent {
+            admin,
+            code_hash,
+            discount,
+            max_uses,
+            expires_at,
+            per_renter_limit,
+        });
+        Ok(())
+    }
+
+    fn disable_promo_code(env: &Env, code_hash: BytesN<32>) -> Result<(), Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if !has_promo_code(env, &code_hash) {
+            return Err(Error::PromoCodeNotFound);
+        }
+
+        remove_promo_code(env, &code_hash);
+        events::promo::promo_code_disabled(env, PromoCodeDisabledEvent { admin, code_hash });
+        Ok(())
+    }
+
+    fn get_promo_code(env: &Env, code_hash: BytesN<32>) -> Option<PromoCode> {
+        read_promo_code(env, &code_hash)
+    }
+
+    fn set_sponsored_renter(env: &Env, sponsor: Address, renter: Address, approved: bool) -> Result<(), Error> {
+        sponsor.require_auth();
+
+        write_sponsored_renter(env, &sponsor, &renter, approved);
+        events::sponsor::sponsored_renter_set(env, SponsoredRenterSetEvent { sponsor, renter, approved });
+        Ok(())
+    }
+
+    fn is_sponsored_renter(env: &Env, sponsor: Address, renter: Address) -> bool {
+        is_sponsored_renter(env, &sponsor, &renter)
+    }
+
+    fn deposit_credit(env: &Env, renter: Address, amount: i128) -> Result<(), Error> {
+        renter.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let balance_after = add_credit(env, &renter, amount)?;
+        let contract_balance = read_contract_balance(env)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        write_contract_balance(env, &contract_balance);
+
+        token_transfer(env, &renter, &env.current_contract_address(), &amount);
+        events::credit::credit_deposited(env, CreditEvent { renter, amount, balance_after });
+        Ok(())
+    }
+
+    fn withdraw_credit(env: &Env, renter: Address, amount: i128) -> Result<(), Error> {
+        renter.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let balance_after = use_credit(env, &renter, amount)?;
+        let contract_balance = read_contract_balance(env)
+            .checked_sub(amount)
+            .ok_or(Error::Underflow)?;
+        write_contract_balance(env, &contract_balance);
+
+        token_transfer(env, &env.current_contract_address(), &renter, &amount);
+        events::credit::credit_withdrawn(env, CreditEvent { renter, amount, balance_after });
+        Ok(())
+    }
+
+    fn get_credit(env: &Env, renter: Address) -> i128 {
+        read_credit(env, &renter)
+    }
+
+    fn set_insurance_plan(env: &Env, premium_per_day: i128, coverage_cap: i128) -> Result<(), Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if premium_per_day < 0 || coverage_cap < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        // A zero premium stops offering coverage; existing coverage still holds
+        if premium_per_day == 0 {
+            remove_insurance_plan(env);
+        } else {
+            if coverage_cap == 0 {
+                return Err(Error::AmountMustBePositive);
+            }
+            write_insurance_plan(env, &InsurancePlan { premium_per_day, coverage_cap });
+        }
+
+        events::insurance::insurance_plan_set(env, InsurancePlanSetEvent { admin, premium_per_day, coverage_cap });
+        Ok(())
+    }
+
+    fn get_insurance_plan(env: &Env) -> Option<InsurancePlan> {
+        read_insurance_plan(env)
+    }
+
+    fn get_insurance_pool(env: &Env) -> InsurancePool {
+        read_insurance_pool(env)
+    }
+
+    fn get_coverage(env: &Env, coverage_id: u64) -> Option<Coverage> {
+        read_coverage(env, coverage_id)
+    }
+
+    fn pay_insurance_claim(env: &Env, coverage_id: u64, amount: i128) -> Result<(), Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        let (coverage, pool, available_after) = pay_claim(env, coverage_id, amount)?;
+        // Further payouts on the same coverage are the same dispute
+        if coverage.claimed == amount {
+            record_dispute_lost(env, &coverage.renter)?;
+        }
+
+        events::insurance::insurance_claim_paid(env, InsuranceClaimPaidEvent {
+            admin,
+            coverage_id,
+            owner: coverage.owner,
+            renter: coverage.renter,
+            amount,
+            claimed: coverage.claimed,
+            pool_after: pool.balance,
+            available_after,
+        });
+        Ok(())
+    }
+
+    fn open_corporate_account(env: &Env, company: Address, monthly_limit: i128) -> Result<(), Error> {
+        company.require_auth();
+
+        if has_corporate_account(env, &company) {
+            return Err(Error::CorporateAccountAlreadyExists);
+        }
+        if monthly_limit <= 0 {
+            return Err(Error::AmountMustBePositive);
         }
 
-        if amount > car.available_to_withdraw {
+        let account = CorporateAccount {
+            monthly_limit,
+            balance: 0,
+            period: 0,
+            period_spent: 0,
+        };
+        write_corporate_account(env, &company, &account);
+
+        events::corporate::corporate_limit_set(env, CorporateLimitSetEvent { company, previous: 0, monthly_limit });
+        Ok(())
+    }
+
+    fn set_corporate_limit(env: &Env, company: Address, monthly_limit: i128) -> Result<(), Error> {
+        company.require_auth();
+
+        if !has_corporate_account(env, &company) {
+            return Err(Error::CorporateAccountNotFound);
+        }
+        if monthly_limit <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let mut account = read_corporate_account(env, &company);
+        let previous = account.monthly_limit;
+        account.monthly_limit = monthly_limit;
+        write_corporate_account(env, &company, &account);
+
+        events::corporate::corporate_limit_set(env, CorporateLimitSetEvent { company, previous, monthly_limit });
+        Ok(())
+    }
+
+    fn set_corporate_driver(env: &Env, company: Address, driver: Address, authorized: bool) -> Result<(), Error> {
+        company.require_auth();
+
+        if !has_corporate_account(env, &company) {
+            return Err(Error::CorporateAccountNotFound);
+        }
+
+        write_corporate_driver(env, &company, &driver, authorized);
+        events::corporate::corporate_driver_set(env, CorporateDriverSetEvent { company, driver, authorized });
+        Ok(())
+    }
+
+    fn fund_corporate_account(env: &Env, company: Address, amount: i128) -> Result<(), Error> {
+        company.require_auth();
+
+        if !has_corporate_account(env, &company) {
+            return Err(Error::CorporateAccountNotFound);
+        }
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let mut account = read_corporate_account(env, &company);
+        account.balance = account.balance
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        let corporate_balances = read_corporate_balances(env)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        let contract_balance = read_contract_balance(env)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+
+        write_corporate_account(env, &company, &account);
+        write_corporate_balances(env, &corporate_balances);
+        write_contract_balance(env, &contract_balance);
+
+        token_transfer(env, &company, &env.current_contract_address(), &amount);
+        events::corporate::corporate_funded(env, CorporateBalanceEvent {
+            company,
+            amount,
+            balance_after: account.balance,
+        });
+        Ok(())
+    }
+
+    fn withdraw_corporate_balance(env: &Env, company: Address, amount: i128) -> Result<(), Error> {
+        company.require_auth();
+
+        if !has_corporate_account(env, &company) {
+            return Err(Error::CorporateAccountNotFound);
+        }
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let mut account = read_corporate_account(env, &company);
+        if account.balance < amount {
             return Err(Error::InsufficientBalance);
         }
-        let mut contract_balance = read_contract_balance(env);
 
-        car.available_to_withdraw = car.available_to_withdraw
+        account.balance -= amount;
+        let corporate_balances = read_corporate_balances(env)
             .checked_sub(amount)
             .ok_or(Error::Underflow)?;
-        contract_balance = contract_balance
+        let contract_balance = read_contract_balance(env)
             .checked_sub(amount)
             .ok_or(Error::Underflow)?;
 
-        write_car(env, &owner, &car);
+        write_corporate_account(env, &company, &account);
+        write_corporate_balances(env, &corporate_balances);
         write_contract_balance(env, &contract_balance);
 
-        token_transfer(env, &env.current_contract_address(), &owner, &amount);
-        events::payout_owner::payout_owner(env, owner, amount);
+        token_transfer(env, &env.current_contract_address(), &company, &amount);
+        events::corporate::corporate_withdrawn(env, CorporateBalanceEvent {
+            company,
+            amount,
+            balance_after: account.balance,
+        });
+        Ok(())
+    }
+
+    fn get_corporate_account(env: &Env, company: Address) -> Result<CorporateAccount, Error> {
+        public::get_corporate_account(env, &company)
+    }
+
+    fn get_corporate_spend(env: &Env, company: Address) -> Result<(i128, i128), Error> {
+        public::get_corporate_spend(env, &company)
+    }
+
+    fn is_corporate_driver(env: &Env, company: Address, driver: Address) -> bool {
+        is_corporate_driver(env, &company, &driver)
+    }
+
+    fn set_revenue_shares(env: &Env, owner: Address, shares: Vec<RevenueShare>) -> Result<(), Error> {
+        let admin = read_admin(env);
+        require_car_holder(env, &owner)?;
+        admin.require_auth();
+
+        // Replaces the whole list. Income already split stays with whoever it
+        // was credited to; only later settlements use the new shares.
+        validate_revenue_shares(&shares)?;
+        write_revenue_shares(env, &owner, &shares);
+
+        events::revenue::revenue_shares_set(env, RevenueSharesSetEvent { owner, admin, shares });
         Ok(())
     }
 
+    fn get_revenue_shares(env: &Env, owner: Address) -> Vec<RevenueShare> {
+        read_revenue_shares(env, &owner)
+    }
+
+    fn get_share_balance(env: &Env, owner: Address, beneficiary: Address) -> i128 {
+        read_share_balance(env, &owner, &beneficiary)
+    }
+
+    fn withdraw_revenue_share(env: &Env, owner: Address, beneficiary: Address) -> Result<i128, Error> {
+        beneficiary.require_auth();
+
+        // Balances outlive the car, so a removed listing can still be withdrawn
+        let amount = read_share_balance(env, &owner, &beneficiary);
+        if amount <= 0 {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let share_balances = read_share_balances(env)
+            .checked_sub(amount)
+            .ok_or(Error::Underflow)?;
+        let contract_balance = read_contract_balance(env)
+            .checked_sub(amount)
+            .ok_or(Error::Underflow)?;
+        write_share_balance(env, &owner, &beneficiary, &0);
+        write_share_balances(env, &share_balances);
+        write_contract_balance(env, &contract_balance);
+
+        // A beneficiary who also owns a car gets paid where their payouts go
+        let paid_to = read_payout_address(env, &beneficiary);
+        token_transfer(env, &env.current_contract_address(), &paid_to, &amount);
+        events::revenue::share_withdrawn(env, ShareWithdrawnEvent { owner, beneficiary, paid_to, amount });
+        Ok(amount)
+    }
+
+    fn set_tax_config(env: &Env, rate_bps: u32, collector: Address) -> Result<(), Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        validate_tax_rate(rate_bps)?;
+
+        // A zero rate keeps the collector so tax already withheld can be remitted
+        let previous_rate_bps = read_tax_rate(env);
+        write_tax_config(env, &TaxConfig { rate_bps, collector: collector.clone() });
+        events::tax::tax_config_set(env, TaxConfigSetEvent { admin, previous_rate_bps, rate_bps, collector });
+        Ok(())
+    }
+
+    fn get_tax_config(env: &Env) -> Option<TaxConfig> {
+        read_tax_config(env)
+    }
+
+    fn get_tax_balance(env: &Env) -> i128 {
+        read_tax_balance(env)
+    }
+
+    fn remit_tax(env: &Env) -> Result<i128, Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        let amount = read_tax_balance(env);
+        let collector = match read_tax_config(env) {
+            Some(config) if amount > 0 => config.collector,
+            _ => return Err(Error::InsufficientBalance),
+        };
+
+        let contract_balance = read_contract_balance(env)
+            .checked_sub(amount)
+            .ok_or(Error::Underflow)?;
+        write_contract_balance(env, &contract_balance);
+        write_tax_balance(env, &0);
+
+        token_transfer(env, &env.current_contract_address(), &collector, &amount);
+        events::tax::tax_remitted(env, TaxRemittedEvent { admin, collector, amount });
+        Ok(amount)
+    }
+
     fn set_admin_fee(env: &Env, fee: i128) -> Result<(), Error> {
         let admin = read_admin(env);
         admin.require_auth();
@@ -236,11 +1229,25 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::AmountMustBePositive);
         }
 
+        let previous_fee = read_admin_fee(env);
         write_admin_fee(env, &fee);
-        events::contract::admin_fee_set(env, fee);
+        events::contract::admin_fee_set(env, AdminFeeSetEvent { admin, previous_fee, fee });
         Ok(())
     }
 
+    fn reconcile(env: &Env) -> Result<ReconcileReport, Error> {
+        build_reconcile_report(env)
+    }
+
+    fn sweep_surplus(env: &Env, token: Address, to: Address) -> Result<i128, Error> {
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        let amount = sweep_surplus(env, &token, &to)?;
+        events::contract::surplus_swept(env, SurplusSweptEvent { admin, token, to, amount });
+        Ok(amount)
+    }
+
     fn get_admin_fee(env: &Env) -> i128 {
         read_admin_fee(env)
     }
@@ -258,6 +1265,7 @@ impl RentACarContractTrait for RentACarContract {
         }
 
         let mut admin_fees_balance = read_admin_fees_balance(env);
+        let balance_before = admin_fees_balance;
 
         if amount > admin_fees_balance {
             return Err(Error::InsufficientBalance);
@@ -276,7 +1284,39 @@ impl RentACarContractTrait for RentACarContract {
         write_contract_balance(env, &contract_balance);
 
         token_transfer(env, &env.current_contract_address(), &admin, &amount);
-        events::payout_owner::admin_fees_withdrawn(env, admin, amount);
+        events::payout_owner::admin_fees_withdrawn(env, AdminFeesWithdrawnEvent {
+            admin,
+            amount,
+            balance_before,
+            balance_after: admin_fees_balance,
+        });
         Ok(())
     }
 }
+
+fn set_owner_block(env: &Env, owner: Address, renter: Address, blocked: bool) -> Result<(), Error> {
+    require_car_holder(env, &owner)?;
+
+    write_blocked_by_owner(env, &owner, &renter, blocked);
+    events::blocklist::renter_blocklist_updated(env, RenterBlocklistEvent {
+        blocked_by: owner,
+        renter,
+        global: false,
+        blocked,
+    });
+    Ok(())
+}
+
+fn set_global_block(env: &Env, renter: Address, blocked: bool) -> Result<(), Error> {
+    let admin = read_admin(env);
+    admin.require_auth();
+
+    write_blocked_globally(env, &renter, blocked);
+    events::blocklist::renter_blocklist_updated(env, RenterBlocklistEvent {
+        blocked_by: admin,
+        renter,
+        global: true,
+        blocked,
+    });
+    Ok(())
+}
@@ -1,10 +1,23 @@
-use soroban_sdk::{Address, Env, Symbol};
-
-pub(crate) fn car_added(env: &Env, owner: Address, price_per_day: i128) {
-    let topics = (Symbol::new(env, "car_added"), owner.clone());
-    
-    env.events().publish(
-        topics,
-        price_per_day
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarAddedEvent {
+    pub owner: Address,
+    pub token_id: u32, // NFT minted to the owner for the listing
+    pub price_per_day: i128,
+    pub commission_amount: i128,
+}
+
+pub(crate) fn car_added(env: &Env, event: CarAddedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "car_added"),
+        event.owner.clone(),
     );
-}
\ No newline at end of file
+
+    env.events().publish(topics, event);
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RenterBlocklistEvent {
+    pub blocked_by: Address, // Car owner, or the admin for the platform-wide list
+    pub renter: Address,
+    pub global: bool,
+    pub blocked: bool,
+}
+
+pub(crate) fn renter_blocklist_updated(env: &Env, event: RenterBlocklistEvent) {
+    let action = if event.blocked { "renter_blocked" } else { "renter_unblocked" };
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, action),
+        event.blocked_by.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarTokenTransferredEvent {
+    pub owner: Address, // The address the car is listed under, which does not change
+    pub token_id: u32,
+    pub from: Address,
+    pub to: Address,
+    pub paid_out: i128, // Settled earnings paid to the previous holder
+}
+
+pub(crate) fn car_token_transferred(env: &Env, event: CarTokenTransferredEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "car_token_transferred"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -1,19 +1,60 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
 
-pub(crate) fn contract_initialized(env: &Env, admin: Address, token: Address) {
-    let topics = (Symbol::new(env, "contract_initialized"),);
+use super::{contract_name, EVENTS_VERSION};
 
-    env.events().publish(
-        topics,
-        (admin, token)
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ContractInitializedEvent {
+    pub admin: Address,
+    pub token: Address,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AdminFeeSetEvent {
+    pub admin: Address,
+    pub previous_fee: i128,
+    pub fee: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SurplusSweptEvent {
+    pub admin: Address,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn contract_initialized(env: &Env, event: ContractInitializedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "contract_initialized"),
+        event.admin.clone(),
     );
+
+    env.events().publish(topics, event);
 }
 
-pub(crate) fn admin_fee_set(env: &Env, fee: i128) {
-    let topics = (Symbol::new(env, "admin_fee_set"),);
+pub(crate) fn admin_fee_set(env: &Env, event: AdminFeeSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "admin_fee_set"),
+        event.admin.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
 
-    env.events().publish(
-        topics,
-        fee
+pub(crate) fn surplus_swept(env: &Env, event: SurplusSweptEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "surplus_swept"),
+        event.admin.clone(),
     );
-}
\ No newline at end of file
+
+    env.events().publish(topics, event);
+}
@@ -0,0 +1,92 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CorporateLimitSetEvent {
+    pub company: Address,
+    pub previous: i128, // 0 when the account was just opened
+    pub monthly_limit: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CorporateDriverSetEvent {
+    pub company: Address,
+    pub driver: Address,
+    pub authorized: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CorporateBalanceEvent {
+    pub company: Address,
+    pub amount: i128,
+    pub balance_after: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CorporateChargedEvent {
+    pub company: Address,
+    pub driver: Address,
+    pub amount: i128,
+    pub period_spent: i128,
+    pub balance_after: i128,
+}
+
+pub(crate) fn corporate_limit_set(env: &Env, event: CorporateLimitSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "corporate_limit_set"),
+        event.company.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn corporate_driver_set(env: &Env, event: CorporateDriverSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "corporate_driver_set"),
+        event.company.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn corporate_funded(env: &Env, event: CorporateBalanceEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "corporate_funded"),
+        event.company.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn corporate_withdrawn(env: &Env, event: CorporateBalanceEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "corporate_withdrawn"),
+        event.company.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn corporate_charged(env: &Env, event: CorporateChargedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "corporate_charged"),
+        event.company.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CreditEvent {
+    pub renter: Address,
+    pub amount: i128,
+    pub balance_after: i128,
+}
+
+pub(crate) fn credit_deposited(env: &Env, event: CreditEvent) {
+    publish(env, "credit_deposited", event);
+}
+
+pub(crate) fn credit_withdrawn(env: &Env, event: CreditEvent) {
+    publish(env, "credit_withdrawn", event);
+}
+
+pub(crate) fn credit_charged(env: &Env, event: CreditEvent) {
+    publish(env, "credit_charged", event);
+}
+
+fn publish(env: &Env, action: &str, event: CreditEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, action),
+        event.renter.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -0,0 +1,68 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct InsurancePlanSetEvent {
+    pub admin: Address,
+    pub premium_per_day: i128, // 0 when coverage is no longer offered
+    pub coverage_cap: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CoveragePurchasedEvent {
+    pub coverage_id: u64, // Claims on this rental refer to it
+    pub owner: Address,
+    pub renter: Address,
+    pub premium: i128,
+    pub coverage_cap: i128,
+    pub pool_after: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct InsuranceClaimPaidEvent {
+    pub admin: Address,
+    pub coverage_id: u64,
+    pub owner: Address,
+    pub renter: Address,
+    pub amount: i128,
+    pub claimed: i128, // Total claimed on this rental so far
+    pub pool_after: i128,
+    pub available_after: i128,
+}
+
+pub(crate) fn insurance_plan_set(env: &Env, event: InsurancePlanSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "insurance_plan_set"),
+        event.admin.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn coverage_purchased(env: &Env, event: CoveragePurchasedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "coverage_purchased"),
+        event.renter.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn insurance_claim_paid(env: &Env, event: InsuranceClaimPaidEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "insurance_claim_paid"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
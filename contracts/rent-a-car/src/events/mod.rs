@@ -1,6 +1,33 @@
+use soroban_sdk::{Env, Symbol};
+
 pub mod contract;
 pub mod remove_car;
+pub mod transfer_car;
+pub mod car_token;
 pub mod payout_owner;
 pub mod rental;
 pub mod add_car;
-pub mod return_car;
\ No newline at end of file
+pub mod return_car;
+pub mod settle;
+pub mod verification;
+pub mod reputation;
+pub mod blocklist;
+pub mod rental_limits;
+pub mod pricing;
+pub mod promo;
+pub mod corporate;
+pub mod credit;
+pub mod sponsor;
+pub mod insurance;
+pub mod tax;
+pub mod revenue;
+
+// Bumped whenever the topic layout or an event payload changes shape.
+// Topics are always (contract name, version, action, subject): the subject is
+// the car (its owner address) for car events and the acting address otherwise.
+// Soroban allows four topics, so every other actor is part of the payload.
+pub const EVENTS_VERSION: u32 = 5;
+
+pub(crate) fn contract_name(env: &Env) -> Symbol {
+    Symbol::new(env, "rent_a_car")
+}
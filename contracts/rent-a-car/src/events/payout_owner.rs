@@ -1,19 +1,83 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
 
-pub(crate) fn payout_owner(env: &Env, owner: Address, amount: i128) {
-    let topics = (Symbol::new(env, "payout"), owner.clone());
+use super::{contract_name, EVENTS_VERSION};
 
-    env.events().publish(
-        topics,
-        amount
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PayoutEvent {
+    pub owner: Address,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub available_before: i128,
+    pub available_after: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BatchPayoutEvent {
+    pub admin: Address,
+    pub owners_paid: u32,
+    pub owners_skipped: u32, // Owners with no car or nothing to withdraw
+    pub total_amount: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PayoutAddressSetEvent {
+    pub owner: Address,
+    pub previous: Address,
+    pub beneficiary: Address,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AdminFeesWithdrawnEvent {
+    pub admin: Address,
+    pub amount: i128,
+    pub balance_before: i128,
+    pub balance_after: i128,
+}
+
+pub(crate) fn payout_owner(env: &Env, event: PayoutEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "payout"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn batch_payout(env: &Env, event: BatchPayoutEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "batch_payout"),
+        event.admin.clone(),
     );
+
+    env.events().publish(topics, event);
 }
 
-pub(crate) fn admin_fees_withdrawn(env: &Env, admin: Address, amount: i128) {
-    let topics = (Symbol::new(env, "admin_fees_withdrawn"), admin.clone());
+pub(crate) fn payout_address_set(env: &Env, event: PayoutAddressSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "payout_address_set"),
+        event.owner.clone(),
+    );
 
-    env.events().publish(
-        topics,
-        amount
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn admin_fees_withdrawn(env: &Env, event: AdminFeesWithdrawnEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "admin_fees_withdrawn"),
+        event.admin.clone(),
     );
-}
\ No newline at end of file
+
+    env.events().publish(topics, event);
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::storage::types::pricing_rule::PricingRule;
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct HourlyPriceSetEvent {
+    pub owner: Address,
+    pub previous: i128,
+    pub price_per_hour: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PricingRulesSetEvent {
+    pub owner: Address,
+    pub rules: Vec<PricingRule>,
+}
+
+pub(crate) fn hourly_price_set(env: &Env, event: HourlyPriceSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "hourly_price_set"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn pricing_rules_set(env: &Env, event: PricingRulesSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "pricing_rules_set"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
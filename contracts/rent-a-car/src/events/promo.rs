@@ -0,0 +1,66 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol};
+
+use crate::storage::types::promo_discount::PromoDiscount;
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PromoCodeCreatedEvent {
+    pub admin: Address,
+    pub code_hash: BytesN<32>,
+    pub discount: PromoDiscount,
+    pub max_uses: u32,
+    pub expires_at: u64,
+    pub per_renter_limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PromoCodeDisabledEvent {
+    pub admin: Address,
+    pub code_hash: BytesN<32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PromoRedeemedEvent {
+    pub owner: Address,
+    pub renter: Address,
+    pub code_hash: BytesN<32>,
+    pub discount: i128,
+    pub uses: u32,
+}
+
+pub(crate) fn promo_code_created(env: &Env, event: PromoCodeCreatedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "promo_code_created"),
+        event.admin.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn promo_code_disabled(env: &Env, event: PromoCodeDisabledEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "promo_code_disabled"),
+        event.admin.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn promo_redeemed(env: &Env, event: PromoRedeemedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "promo_redeemed"),
+        event.renter.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -1,10 +1,24 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
 
-pub(crate) fn car_removed(env: &Env, owner: Address) {
-    let topics = (Symbol::new(env, "car_removed"), owner.clone());
+use super::{contract_name, EVENTS_VERSION};
 
-    env.events().publish(
-        topics,
-        ()
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarRemovedEvent {
+    pub owner: Address,
+    pub token_id: u32, // Burned with the listing
+    pub price_per_day: i128,
+    pub commission_amount: i128,
+    pub paid_out: i128, // Settled earnings paid to the owner on removal
+}
+
+pub(crate) fn car_removed(env: &Env, event: CarRemovedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "car_removed"),
+        event.owner.clone(),
     );
-}
\ No newline at end of file
+
+    env.events().publish(topics, event);
+}
@@ -1,16 +1,34 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
 
-pub(crate) fn rented(
-    env: &Env,
-    renter: Address,
-    owner: Address,
-    total_days: u32,
-    amount: i128
-) {
-    let topics = (Symbol::new(env, "rented"), renter.clone(), owner.clone());
+use crate::storage::types::rental_unit::RentalUnit;
 
-    env.events().publish(
-        topics,
-        (total_days, amount)
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentedEvent {
+    pub owner: Address,
+    pub renter: Address,
+    pub payer: Address,
+    pub unit: RentalUnit,
+    pub units: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub base_rent: i128,
+    pub commission: i128,
+    pub tax: i128,
+    pub total_amount: i128,
+    pub pending_before: i128,
+    pub pending_after: i128,
+}
+
+pub(crate) fn rented(env: &Env, event: RentedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "rented"),
+        event.owner.clone(),
     );
-}
\ No newline at end of file
+
+    env.events().publish(topics, event);
+}
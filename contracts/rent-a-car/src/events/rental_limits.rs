@@ -0,0 +1,22 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentalLimitsSetEvent {
+    pub owner: Address,
+    pub min_days: u32,
+    pub max_days: u32,
+}
+
+pub(crate) fn rental_limits_set(env: &Env, event: RentalLimitsSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "rental_limits_set"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -0,0 +1,45 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RatingSubmittedEvent {
+    pub owner: Address,
+    pub renter: Address,
+    pub rater: Address,
+    pub rated: Address,
+    pub score: u32,
+    pub rating_count: u32, // Aggregates of the rated address after this rating
+    pub rating_sum: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MinRenterScoreSetEvent {
+    pub owner: Address,
+    pub previous: u32,
+    pub min_renter_score: u32,
+}
+
+pub(crate) fn rating_submitted(env: &Env, event: RatingSubmittedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "rating_submitted"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn min_renter_score_set(env: &Env, event: MinRenterScoreSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "min_renter_score_set"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -1,15 +1,25 @@
-use soroban_sdk::{Address, Env, Symbol};
-
-pub(crate) fn car_returned(
-    env: &Env,
-    renter: Address,
-    owner: Address,
-) {
-    let topics = (Symbol::new(env, "car_returned"), renter.clone(), owner.clone());
-
-    env.events().publish(
-        topics,
-        ()
-    );
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarReturnedEvent {
+    pub owner: Address,
+    pub renter: Address,
+    pub returned_at: u64,
+    pub settled_amount: i128, // Escrow moved to the owner's settled balance on return
+    pub available_before: i128,
+    pub available_after: i128,
 }
 
+pub(crate) fn car_returned(env: &Env, event: CarReturnedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "car_returned"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::storage::structs::revenue_share::RevenueShare;
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RevenueSharesSetEvent {
+    pub owner: Address,
+    pub admin: Address,
+    pub shares: Vec<RevenueShare>, // Empty when the owner keeps all of the income again
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RevenueSplitEvent {
+    pub owner: Address,
+    pub amount: i128,
+    pub shares: Vec<RevenueShare>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ShareWithdrawnEvent {
+    pub owner: Address,
+    pub beneficiary: Address,
+    pub paid_to: Address, // The beneficiary's payout address, itself unless set
+    pub amount: i128,
+}
+
+pub(crate) fn revenue_shares_set(env: &Env, event: RevenueSharesSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "revenue_shares_set"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn revenue_split(env: &Env, event: RevenueSplitEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "revenue_split"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn share_withdrawn(env: &Env, event: ShareWithdrawnEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "share_withdrawn"),
+        event.beneficiary.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
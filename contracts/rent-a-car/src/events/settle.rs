@@ -0,0 +1,26 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentalSettledEvent {
+    pub owner: Address,
+    pub renter: Address,
+    pub amount: i128,
+    pub settled_before: i128, // Part of the rental settled before this call
+    pub settled_after: i128,
+    pub available_after: i128,
+    pub pending_after: i128,
+}
+
+pub(crate) fn rental_settled(env: &Env, event: RentalSettledEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "rental_settled"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
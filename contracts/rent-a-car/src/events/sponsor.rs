@@ -0,0 +1,22 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SponsoredRenterSetEvent {
+    pub sponsor: Address,
+    pub renter: Address,
+    pub approved: bool,
+}
+
+pub(crate) fn sponsored_renter_set(env: &Env, event: SponsoredRenterSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "sponsored_renter_set"),
+        event.sponsor.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
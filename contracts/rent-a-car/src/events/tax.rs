@@ -0,0 +1,42 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TaxConfigSetEvent {
+    pub admin: Address,
+    pub previous_rate_bps: u32,
+    pub rate_bps: u32,
+    pub collector: Address,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TaxRemittedEvent {
+    pub admin: Address,
+    pub collector: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn tax_config_set(env: &Env, event: TaxConfigSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "tax_config_set"),
+        event.admin.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn tax_remitted(env: &Env, event: TaxRemittedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "tax_remitted"),
+        event.admin.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarTransferredEvent {
+    pub owner: Address, // Seller, the address the car was listed under
+    pub new_owner: Address,
+    pub token_id: u32,
+    pub paid_out: i128, // Settled earnings paid to the seller on transfer
+}
+
+pub(crate) fn car_transferred(env: &Env, event: CarTransferredEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "car_transferred"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
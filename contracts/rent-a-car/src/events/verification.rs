@@ -0,0 +1,79 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use super::{contract_name, EVENTS_VERSION};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AttestorSetEvent {
+    pub admin: Address,
+    pub previous: Option<Address>,
+    pub attestor: Option<Address>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RenterVerifiedEvent {
+    pub renter: Address,
+    pub verifier: Address,
+    pub previous_expires_at: Option<u64>,
+    pub expires_at: Option<u64>, // None when the verification is revoked
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VerifiedRentersOnlySetEvent {
+    pub owner: Address,
+    pub enabled: bool,
+}
+
+pub(crate) fn attestor_set(env: &Env, event: AttestorSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "attestor_set"),
+        event.admin.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn renter_verification_set(env: &Env, event: RenterVerifiedEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "renter_verification_set"),
+        event.renter.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+pub(crate) fn verified_renters_only_set(env: &Env, event: VerifiedRentersOnlySetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "verified_renters_only_set"),
+        event.owner.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct EligibilityVerifierSetEvent {
+    pub admin: Address,
+    pub previous: Option<Address>,
+    pub verifier: Option<Address>,
+}
+
+pub(crate) fn eligibility_verifier_set(env: &Env, event: EligibilityVerifierSetEvent) {
+    let topics = (
+        contract_name(env),
+        EVENTS_VERSION,
+        Symbol::new(env, "eligibility_verifier_set"),
+        event.admin.clone(),
+    );
+
+    env.events().publish(topics, event);
+}
@@ -1,22 +1,118 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
 
-use crate::storage::types::{
-    car_status::CarStatus,
-    errors::Error,
+use crate::storage::{
+    structs::{
+        corporate_account::CorporateAccount, insurance::{Coverage, InsurancePlan, InsurancePool}, promo_code::PromoCode,
+        quote::Quote, reconcile_report::ReconcileReport, reputation::Reputation, tax_config::TaxConfig,
+        revenue_share::RevenueShare,
+    },
+    types::{
+        car_status::CarStatus, errors::Error, pricing_rule::PricingRule, promo_discount::PromoDiscount,
+        payment_source::PaymentSource, rental_unit::RentalUnit,
+    },
 };
 pub trait RentACarContractTrait {
     fn __constructor(env: &Env, admin: Address, token: Address)-> Result<(), Error>;
     //fn initialize(env: &Env, admin: Address, token: Address);
     fn get_admin(env: &Env) -> Address;
-    fn add_car(env: &Env, owner: Address, price_per_day: i128, commission_amount: i128)-> Result<(), Error>;
+    fn add_car(
+        env: &Env,
+        owner: Address,
+        price_per_day: i128,
+        commission_amount: i128,
+        min_days: u32,
+        max_days: u32,
+    ) -> Result<(), Error>;
     fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error>;
     fn get_car_info(env: &Env, owner: Address) -> Result<(i128, i128), Error>;
+    fn get_car_balances(env: &Env, owner: Address) -> Result<(i128, i128), Error>;
     fn has_rental(env: &Env, renter: Address, owner: Address) -> bool;
-    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128)-> Result<(), Error>;
+    fn quote_rental(env: &Env, owner: Address, unit: RentalUnit, units: u32, start: u64) -> Result<Quote, Error>;
+    #[allow(clippy::too_many_arguments)]
+    fn rental(
+        env: &Env,
+        renter: Address,
+        owner: Address,
+        unit: RentalUnit,
+        units: u32,
+        amount: i128,
+        promo_code: Option<Bytes>,
+        payment: PaymentSource,
+        insured: bool,
+    ) -> Result<(), Error>;
     fn return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error>;
+    fn settle(env: &Env, owner: Address) -> Result<i128, Error>;
     fn remove_car(env: &Env, owner: Address)-> Result<(), Error>;
+    fn transfer_car(env: &Env, owner: Address, new_owner: Address) -> Result<(), Error>;
+    fn transfer(env: &Env, from: Address, to: Address, token_id: u32) -> Result<(), Error>;
+    fn balance(env: &Env, account: Address) -> u32;
+    fn owner_of(env: &Env, token_id: u32) -> Result<Address, Error>;
+    fn get_car_token(env: &Env, owner: Address) -> Result<u32, Error>;
     fn payout_owner(env: &Env, owner: Address, amount: i128)-> Result<(), Error>;
+    fn payout_owner_all(env: &Env, owner: Address) -> Result<i128, Error>;
+    fn batch_payout(env: &Env, owners: Vec<Address>) -> Result<i128, Error>;
+    fn set_payout_address(env: &Env, owner: Address, beneficiary: Address) -> Result<(), Error>;
+    fn get_payout_address(env: &Env, owner: Address) -> Address;
+    fn set_attestor(env: &Env, attestor: Option<Address>) -> Result<(), Error>;
+    fn set_eligibility_verifier(env: &Env, verifier: Option<Address>) -> Result<(), Error>;
+    fn get_eligibility_verifier(env: &Env) -> Option<Address>;
+    fn verify_renter(env: &Env, verifier: Address, renter: Address, expires_at: u64) -> Result<(), Error>;
+    fn revoke_renter_verification(env: &Env, verifier: Address, renter: Address) -> Result<(), Error>;
+    fn get_renter_verification(env: &Env, renter: Address) -> Option<u64>;
+    fn set_verified_renters_only(env: &Env, owner: Address, enabled: bool) -> Result<(), Error>;
+    fn rate_renter(env: &Env, owner: Address, renter: Address, score: u32) -> Result<(), Error>;
+    fn rate_owner(env: &Env, renter: Address, owner: Address, score: u32) -> Result<(), Error>;
+    fn get_reputation(env: &Env, address: Address) -> Reputation;
+    fn set_hourly_price(env: &Env, owner: Address, price_per_hour: i128) -> Result<(), Error>;
+    fn set_pricing_rules(env: &Env, owner: Address, rules: Vec<PricingRule>) -> Result<(), Error>;
+    fn get_pricing_rules(env: &Env, owner: Address) -> Result<Vec<PricingRule>, Error>;
+    fn set_rental_limits(env: &Env, owner: Address, min_days: u32, max_days: u32) -> Result<(), Error>;
+    fn get_rental_limits(env: &Env, owner: Address) -> Result<(u32, u32), Error>;
+    fn set_min_renter_score(env: &Env, owner: Address, min_renter_score: u32) -> Result<(), Error>;
+    fn block_renter(env: &Env, owner: Address, renter: Address) -> Result<(), Error>;
+    fn unblock_renter(env: &Env, owner: Address, renter: Address) -> Result<(), Error>;
+    fn block_renter_globally(env: &Env, renter: Address) -> Result<(), Error>;
+    fn unblock_renter_globally(env: &Env, renter: Address) -> Result<(), Error>;
+    fn is_renter_blocked(env: &Env, owner: Address, renter: Address) -> bool;
+    fn create_promo_code(
+        env: &Env,
+        code_hash: BytesN<32>,
+        discount: PromoDiscount,
+        max_uses: u32,
+        expires_at: u64,
+        per_renter_limit: Option<u32>,
+    ) -> Result<(), Error>;
+    fn disable_promo_code(env: &Env, code_hash: BytesN<32>) -> Result<(), Error>;
+    fn get_promo_code(env: &Env, code_hash: BytesN<32>) -> Option<PromoCode>;
+    fn set_sponsored_renter(env: &Env, sponsor: Address, renter: Address, approved: bool) -> Result<(), Error>;
+    fn is_sponsored_renter(env: &Env, sponsor: Address, renter: Address) -> bool;
+    fn deposit_credit(env: &Env, renter: Address, amount: i128) -> Result<(), Error>;
+    fn withdraw_credit(env: &Env, renter: Address, amount: i128) -> Result<(), Error>;
+    fn get_credit(env: &Env, renter: Address) -> i128;
+    fn set_insurance_plan(env: &Env, premium_per_day: i128, coverage_cap: i128) -> Result<(), Error>;
+    fn get_insurance_plan(env: &Env) -> Option<InsurancePlan>;
+    fn get_insurance_pool(env: &Env) -> InsurancePool;
+    fn get_coverage(env: &Env, coverage_id: u64) -> Option<Coverage>;
+    fn pay_insurance_claim(env: &Env, coverage_id: u64, amount: i128) -> Result<(), Error>;
+    fn open_corporate_account(env: &Env, company: Address, monthly_limit: i128) -> Result<(), Error>;
+    fn set_corporate_limit(env: &Env, company: Address, monthly_limit: i128) -> Result<(), Error>;
+    fn set_corporate_driver(env: &Env, company: Address, driver: Address, authorized: bool) -> Result<(), Error>;
+    fn fund_corporate_account(env: &Env, company: Address, amount: i128) -> Result<(), Error>;
+    fn withdraw_corporate_balance(env: &Env, company: Address, amount: i128) -> Result<(), Error>;
+    fn get_corporate_account(env: &Env, company: Address) -> Result<CorporateAccount, Error>;
+    fn get_corporate_spend(env: &Env, company: Address) -> Result<(i128, i128), Error>;
+    fn is_corporate_driver(env: &Env, company: Address, driver: Address) -> bool;
+    fn set_revenue_shares(env: &Env, owner: Address, shares: Vec<RevenueShare>) -> Result<(), Error>;
+    fn get_revenue_shares(env: &Env, owner: Address) -> Vec<RevenueShare>;
+    fn get_share_balance(env: &Env, owner: Address, beneficiary: Address) -> i128;
+    fn withdraw_revenue_share(env: &Env, owner: Address, beneficiary: Address) -> Result<i128, Error>;
+    fn set_tax_config(env: &Env, rate_bps: u32, collector: Address) -> Result<(), Error>;
+    fn get_tax_config(env: &Env) -> Option<TaxConfig>;
+    fn get_tax_balance(env: &Env) -> i128;
+    fn remit_tax(env: &Env) -> Result<i128, Error>;
     fn set_admin_fee(env: &Env, fee: i128) -> Result<(), Error>;
+    fn reconcile(env: &Env) -> Result<ReconcileReport, Error>;
+    fn sweep_surplus(env: &Env, token: Address, to: Address) -> Result<i128, Error>;
     fn get_admin_fee(env: &Env) -> i128;
     fn get_admin_fees_balance(env: &Env) -> i128;
     fn withdraw_admin_fees(env: &Env, amount: i128) -> Result<(), Error>;
@@ -0,0 +1,9 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+// Implemented by external compliance contracts plugged in with
+// `set_eligibility_verifier`. The car is identified by its owner address.
+#[contractclient(name = "EligibilityVerifierClient")]
+#[allow(dead_code)] // Implemented by external verifier contracts
+pub trait EligibilityVerifier {
+    fn is_eligible(env: Env, renter: Address, car: Address) -> bool;
+}
@@ -1 +1,2 @@
-pub mod contract;
\ No newline at end of file
+pub mod contract;
+pub mod eligibility;
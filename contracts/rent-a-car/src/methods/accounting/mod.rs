@@ -0,0 +1,4 @@
+mod reconcile;
+mod sweep;
+pub use reconcile::build_reconcile_report;
+pub use sweep::sweep_surplus;
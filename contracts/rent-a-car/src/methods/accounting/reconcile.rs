@@ -0,0 +1,57 @@
+use soroban_sdk::{token, Env};
+use crate::storage::{
+    admin_fees_balance::read_admin_fees_balance,
+    car::{read_owner_balances, read_pending_balances},
+    contract_balance::read_contract_balance,
+    corporate::read_corporate_balances,
+    credit::read_credit_balances,
+    insurance::read_insurance_pool,
+    tax::read_tax_balance,
+    revenue_share::read_share_balances,
+    structs::reconcile_report::ReconcileReport,
+    token::read_token,
+    types::errors::Error,
+};
+
+pub fn build_reconcile_report(env: &Env) -> Result<ReconcileReport, Error> {
+    let owner_balances = read_owner_balances(env);
+    let deposits = read_pending_balances(env);
+    let admin_fees_balance = read_admin_fees_balance(env);
+    let corporate_balances = read_corporate_balances(env);
+    let credit_balances = read_credit_balances(env);
+    let insurance_pool = read_insurance_pool(env).balance;
+    let tax_balance = read_tax_balance(env);
+    let share_balances = read_share_balances(env);
+    let liabilities = owner_balances
+        .checked_add(deposits)
+        .and_then(|total| total.checked_add(admin_fees_balance))
+        .and_then(|total| total.checked_add(corporate_balances))
+        .and_then(|total| total.checked_add(credit_balances))
+        .and_then(|total| total.checked_add(insurance_pool))
+        .and_then(|total| total.checked_add(tax_balance))
+        .and_then(|total| total.checked_add(share_balances))
+        .ok_or(Error::Overflow)?;
+
+    let tracked_balance = read_contract_balance(env);
+    let token_balance = token::TokenClient::new(env, &read_token(env))
+        .balance(&env.current_contract_address());
+    let surplus = token_balance
+        .checked_sub(liabilities)
+        .ok_or(Error::Underflow)?;
+
+    Ok(ReconcileReport {
+        tracked_balance,
+        token_balance,
+        owner_balances,
+        deposits,
+        admin_fees_balance,
+        corporate_balances,
+        credit_balances,
+        insurance_pool,
+        tax_balance,
+        share_balances,
+        liabilities,
+        surplus,
+        is_consistent: tracked_balance == liabilities && surplus >= 0,
+    })
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::{token, Address, Env};
+use crate::{
+    methods::accounting::build_reconcile_report,
+    storage::{token::read_token, types::errors::Error},
+};
+
+// Only what exceeds the contract's liabilities can be swept. The contract
+// holds no liabilities in any token other than the rental token.
+pub fn surplus_of(env: &Env, token_address: &Address) -> Result<i128, Error> {
+    if *token_address == read_token(env) {
+        return Ok(build_reconcile_report(env)?.surplus);
+    }
+
+    Ok(token::TokenClient::new(env, token_address).balance(&env.current_contract_address()))
+}
+
+pub fn sweep_surplus(env: &Env, token_address: &Address, to: &Address) -> Result<i128, Error> {
+    let surplus = surplus_of(env, token_address)?;
+    if surplus <= 0 {
+        return Err(Error::NoSurplusToSweep);
+    }
+
+    token::TokenClient::new(env, token_address).transfer(&env.current_contract_address(), to, &surplus);
+    Ok(surplus)
+}
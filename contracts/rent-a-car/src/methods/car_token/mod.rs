@@ -0,0 +1,4 @@
+mod ownership;
+pub use ownership::{
+    burn_car_token, car_holder, car_payout_address, mint_car_token, move_car_token, require_car_holder,
+};
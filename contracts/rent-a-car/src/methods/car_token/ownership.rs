@@ -0,0 +1,80 @@
+use soroban_sdk::{Address, Env};
+use crate::{
+    methods::payout::pay_owner,
+    storage::{
+        car::{has_car, read_car},
+        car_token::{
+            next_car_token_id, read_car_token_balance, read_car_token_holder, remove_car_token,
+            write_car_token, write_car_token_balance,
+        },
+        payout_address::{has_payout_address, read_payout_address, remove_payout_address},
+        revenue_share::remove_revenue_shares,
+        types::{car_status::CarStatus, errors::Error},
+    },
+};
+
+// Each listing is an NFT. `owner` stays the key the car is listed under, but
+// whoever holds the token decides payouts, settings and delisting. The entry
+// points follow OpenZeppelin's NonFungibleToken (`balance`, `owner_of`,
+// `transfer`), so `stellar-non-fungible` can take over the token storage.
+pub fn mint_car_token(env: &Env, owner: &Address) -> u32 {
+    let token_id = next_car_token_id(env);
+    write_car_token(env, token_id, owner, owner);
+    write_car_token_balance(env, owner, read_car_token_balance(env, owner) + 1);
+    token_id
+}
+
+pub fn burn_car_token(env: &Env, token_id: u32) {
+    let holder = read_car_token_holder(env, token_id);
+    write_car_token_balance(env, &holder, read_car_token_balance(env, &holder) - 1);
+    remove_car_token(env, token_id);
+}
+
+// Callers check that the car exists
+pub fn car_holder(env: &Env, owner: &Address) -> Address {
+    read_car_token_holder(env, read_car(env, owner).token_id)
+}
+
+pub fn require_car_holder(env: &Env, owner: &Address) -> Result<Address, Error> {
+    if !has_car(env, owner) {
+        return Err(Error::CarNotFound);
+    }
+
+    let holder = car_holder(env, owner);
+    holder.require_auth();
+    Ok(holder)
+}
+
+// Where the listing's payouts go: the address the holder set, or the holder
+pub fn car_payout_address(env: &Env, owner: &Address) -> Address {
+    if has_payout_address(env, owner) {
+        read_payout_address(env, owner)
+    } else {
+        car_holder(env, owner)
+    }
+}
+
+// Hands the listing's token to `to` and returns what was paid out to the
+// previous holder. Earnings settled so far are theirs and co-owner shares and
+// the payout address were their choice, so all of that is wound up first.
+pub fn move_car_token(env: &Env, owner: &Address, to: &Address) -> Result<i128, Error> {
+    let car = read_car(env, owner);
+
+    // Rental escrow is settled against the listing, so it can't move mid-rental
+    if car.car_status == CarStatus::Rented {
+        return Err(Error::CarNotReturned);
+    }
+
+    let paid_out = car.available_to_withdraw;
+    if paid_out > 0 {
+        pay_owner(env, owner, paid_out)?;
+    }
+    remove_revenue_shares(env, owner);
+    remove_payout_address(env, owner);
+
+    let from = read_car_token_holder(env, car.token_id);
+    write_car_token_balance(env, &from, read_car_token_balance(env, &from) - 1);
+    write_car_token_balance(env, to, read_car_token_balance(env, to) + 1);
+    write_car_token(env, car.token_id, to, owner);
+    Ok(paid_out)
+}
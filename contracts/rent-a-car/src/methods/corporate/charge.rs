@@ -0,0 +1,79 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events::{self, corporate::CorporateChargedEvent},
+    methods::pricing::SECONDS_PER_DAY,
+    storage::{
+        corporate::{
+            has_corporate_account, is_corporate_driver, read_corporate_account, read_corporate_balances,
+            write_corporate_account, write_corporate_balances,
+        },
+        structs::corporate_account::CorporateAccount,
+        types::errors::Error,
+    },
+};
+
+// Calendar month (UTC) of a timestamp as `year * 12 + month - 1`, using the
+// days-to-civil conversion from Howard Hinnant's date algorithms
+pub fn month_index(timestamp: u64) -> u64 {
+    let z = timestamp / SECONDS_PER_DAY + 719_468; // Days since 0000-03-01
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153; // 0 is March
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    year * 12 + month - 1
+}
+
+// Starts a new spending period when the calendar month has changed
+pub fn roll_period(account: &mut CorporateAccount, now: u64) {
+    let period = month_index(now);
+    if account.period != period {
+        account.period = period;
+        account.period_spent = 0;
+    }
+}
+
+// Pays `amount` for `driver` out of the account's prepaid balance. The funds
+// are already held by the contract, so only the buckets move.
+pub fn charge_corporate_account(env: &Env, company: &Address, driver: &Address, amount: i128) -> Result<(), Error> {
+    if !has_corporate_account(env, company) {
+        return Err(Error::CorporateAccountNotFound);
+    }
+    if !is_corporate_driver(env, company, driver) {
+        return Err(Error::NotCorporateDriver);
+    }
+
+    let mut account = read_corporate_account(env, company);
+    roll_period(&mut account, env.ledger().timestamp());
+
+    let period_spent = account.period_spent
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    if period_spent > account.monthly_limit {
+        return Err(Error::CorporateLimitExceeded);
+    }
+    if account.balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    account.period_spent = period_spent;
+    account.balance -= amount;
+    let corporate_balances = read_corporate_balances(env)
+        .checked_sub(amount)
+        .ok_or(Error::Underflow)?;
+
+    write_corporate_account(env, company, &account);
+    write_corporate_balances(env, &corporate_balances);
+
+    events::corporate::corporate_charged(env, CorporateChargedEvent {
+        company: company.clone(),
+        driver: driver.clone(),
+        amount,
+        period_spent: account.period_spent,
+        balance_after: account.balance,
+    });
+    Ok(())
+}
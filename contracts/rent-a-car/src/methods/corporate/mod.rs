@@ -0,0 +1,2 @@
+mod charge;
+pub use charge::{charge_corporate_account, roll_period};
@@ -0,0 +1,16 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::{
+    blocklist::{is_blocked_by_owner, is_blocked_globally},
+    types::errors::Error,
+};
+
+// Must run in every entry point that books a car
+pub fn check_renter_not_blocked(env: &Env, owner: &Address, renter: &Address) -> Result<(), Error> {
+    if is_blocked_globally(env, renter) {
+        return Err(Error::RenterBlockedGlobally);
+    }
+    if is_blocked_by_owner(env, owner, renter) {
+        return Err(Error::RenterBlockedByOwner);
+    }
+    Ok(())
+}
@@ -0,0 +1,8 @@
+mod blocklist;
+mod renter_score;
+mod verified_renter;
+mod verifier;
+pub use blocklist::check_renter_not_blocked;
+pub use renter_score::check_renter_score;
+pub use verified_renter::{check_renter_verified, require_verifier};
+pub use verifier::check_renter_eligible;
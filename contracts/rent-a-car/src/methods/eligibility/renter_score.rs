@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, Env};
+use crate::{
+    methods::reputation::average_score,
+    storage::{reputation::read_reputation, types::errors::Error},
+};
+
+// Renters without ratings don't meet a minimum score: owners who set one
+// opted out of unknown renters.
+pub fn check_renter_score(env: &Env, renter: &Address, min_renter_score: u32) -> Result<(), Error> {
+    if min_renter_score == 0 {
+        return Ok(());
+    }
+
+    match average_score(&read_reputation(env, renter)) {
+        Some(score) if score >= min_renter_score => Ok(()),
+        _ => Err(Error::RenterScoreTooLow),
+    }
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::{
+    admin::read_admin,
+    verification::{read_attestor, read_renter_verification},
+    types::errors::Error,
+};
+
+// Renter verification can be managed by the admin or the configured attestor
+pub fn require_verifier(env: &Env, verifier: &Address) -> Result<(), Error> {
+    verifier.require_auth();
+
+    if *verifier == read_admin(env) || Some(verifier.clone()) == read_attestor(env) {
+        return Ok(());
+    }
+    Err(Error::Unauthorized)
+}
+
+pub fn check_renter_verified(env: &Env, renter: &Address) -> Result<(), Error> {
+    match read_renter_verification(env, renter) {
+        None => Err(Error::RenterNotVerified),
+        Some(expires_at) if expires_at <= env.ledger().timestamp() => Err(Error::RenterVerificationExpired),
+        Some(_) => Ok(()),
+    }
+}
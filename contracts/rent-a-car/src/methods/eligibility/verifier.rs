@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env};
+use crate::{
+    interfaces::eligibility::EligibilityVerifierClient,
+    storage::{verification::read_eligibility_verifier, types::errors::Error},
+};
+
+// A verifier that panics or returns something other than a bool blocks the
+// rental instead of aborting the whole transaction with a host error.
+pub fn check_renter_eligible(env: &Env, renter: &Address, owner: &Address) -> Result<(), Error> {
+    let Some(verifier) = read_eligibility_verifier(env) else {
+        return Ok(());
+    };
+
+    match EligibilityVerifierClient::new(env, &verifier).try_is_eligible(renter, owner) {
+        Ok(Ok(true)) => Ok(()),
+        Ok(Ok(false)) => Err(Error::RenterNotEligible),
+        _ => Err(Error::EligibilityCheckFailed),
+    }
+}
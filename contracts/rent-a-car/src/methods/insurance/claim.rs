@@ -0,0 +1,69 @@
+use soroban_sdk::Env;
+
+use crate::{
+    methods::{revenue::split_settled, token::token::token_transfer},
+    storage::{
+        car::{read_car, write_car},
+        car_token::{has_car_token, read_car_token_holder, read_car_token_listing},
+        contract_balance::{read_contract_balance, write_contract_balance},
+        insurance::{read_coverage, read_insurance_pool, write_coverage, write_insurance_pool},
+        structs::insurance::{Coverage, InsurancePool},
+        types::errors::Error,
+    },
+};
+
+// Pays a damage claim on a covered rental out of the pool into the car's
+// withdrawable balance, split with co-owners like rental income. Tokens stay
+// in the contract, so ContractBalance does not move. Returns the car's
+// withdrawable balance after the claim.
+//
+// If whoever held the car when the rental was insured has since sold it or
+// delisted it, the claim is paid to them directly and 0 is returned.
+pub fn pay_claim(env: &Env, coverage_id: u64, amount: i128) -> Result<(Coverage, InsurancePool, i128), Error> {
+    if amount <= 0 {
+        return Err(Error::AmountMustBePositive);
+    }
+
+    // Claims draw on what is left of the coverage; an unknown id has none
+    let mut coverage = read_coverage(env, coverage_id).ok_or(Error::InsufficientBalance)?;
+    coverage.claimed = coverage.claimed
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    if coverage.claimed > coverage.coverage_cap {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let mut pool = read_insurance_pool(env);
+    if pool.balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    pool.balance -= amount;
+    pool.claims_paid = pool.claims_paid
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+
+    write_coverage(env, coverage_id, &coverage);
+    write_insurance_pool(env, &pool);
+
+    let still_held = has_car_token(env, coverage.token_id)
+        && read_car_token_holder(env, coverage.token_id) == coverage.holder;
+    if !still_held {
+        let contract_balance = read_contract_balance(env)
+            .checked_sub(amount)
+            .ok_or(Error::Underflow)?;
+        write_contract_balance(env, &contract_balance);
+        token_transfer(env, &env.current_contract_address(), &coverage.holder, &amount);
+        return Ok((coverage, pool, 0));
+    }
+
+    // Found through the token, as the holder may have relisted the car under
+    // their own address with transfer_car
+    let owner = read_car_token_listing(env, coverage.token_id);
+    let mut car = read_car(env, &owner);
+    car.available_to_withdraw = car.available_to_withdraw
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    split_settled(env, &owner, &mut car, amount)?;
+    write_car(env, &owner, &car);
+    Ok((coverage, pool, car.available_to_withdraw))
+}
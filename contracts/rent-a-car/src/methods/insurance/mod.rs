@@ -0,0 +1,4 @@
+mod claim;
+mod premium;
+pub use claim::pay_claim;
+pub use premium::buy_coverage;
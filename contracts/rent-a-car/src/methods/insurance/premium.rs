@@ -0,0 +1,57 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    methods::pricing::SECONDS_PER_DAY,
+    storage::{
+        car_token::read_car_token_holder,
+        insurance::{next_coverage_id, read_insurance_plan, read_insurance_pool, write_coverage, write_insurance_pool},
+        structs::insurance::{Coverage, InsurancePlan},
+        types::errors::Error,
+    },
+};
+
+// Every started day pays a full premium, so hourly rentals pay for one day
+pub fn compute_premium(plan: &InsurancePlan, duration: u64) -> Result<i128, Error> {
+    let days = duration.div_ceil(SECONDS_PER_DAY);
+
+    plan.premium_per_day
+        .checked_mul(days as i128)
+        .ok_or(Error::Overflow)
+}
+
+// Records the coverage under a new id and adds the premium to the pool. The
+// caller collects the premium along with the rest of the rental and moves
+// ContractBalance.
+pub fn buy_coverage(
+    env: &Env,
+    renter: &Address,
+    owner: &Address,
+    token_id: u32,
+    duration: u64,
+) -> Result<(u64, Coverage), Error> {
+    let plan = read_insurance_plan(env).ok_or(Error::InsuranceNotOffered)?;
+    let premium = compute_premium(&plan, duration)?;
+
+    let mut pool = read_insurance_pool(env);
+    pool.balance = pool.balance
+        .checked_add(premium)
+        .ok_or(Error::Overflow)?;
+    pool.premiums_collected = pool.premiums_collected
+        .checked_add(premium)
+        .ok_or(Error::Overflow)?;
+
+    let coverage = Coverage {
+        owner: owner.clone(),
+        token_id,
+        holder: read_car_token_holder(env, token_id),
+        renter: renter.clone(),
+        premium,
+        coverage_cap: plan.coverage_cap,
+        claimed: 0,
+    };
+
+    write_insurance_pool(env, &pool);
+    let coverage_id = next_coverage_id(env);
+    write_coverage(env, coverage_id, &coverage);
+    Ok((coverage_id, coverage))
+}
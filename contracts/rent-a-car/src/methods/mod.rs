@@ -1,2 +1,14 @@
+pub mod accounting;
+pub mod eligibility;
 pub mod token;
-pub mod public;
\ No newline at end of file
+pub mod public;
+pub mod payout;
+pub mod pricing;
+pub mod promo;
+pub mod corporate;
+pub mod insurance;
+pub mod revenue;
+pub mod payment;
+pub mod reputation;
+pub mod settlement;
+pub mod car_token;
\ No newline at end of file
@@ -0,0 +1,40 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    methods::{
+        corporate::charge_corporate_account,
+        payment::credit::charge_credit,
+        token::token::{token_transfer, token_transfer_from},
+    },
+    storage::{
+        contract_balance::{read_contract_balance, write_contract_balance},
+        sponsor::is_sponsored_renter,
+        types::{errors::Error, payment_source::PaymentSource},
+    },
+};
+
+// Brings the rental total into the contract from whoever pays for it
+pub fn collect_payment(env: &Env, renter: &Address, payment: &PaymentSource, amount: i128) -> Result<(), Error> {
+    let contract_address = env.current_contract_address();
+
+    match payment {
+        // Credit and corporate funds are already held and counted in ContractBalance
+        PaymentSource::Credit => return charge_credit(env, renter, amount),
+        PaymentSource::Corporate(company) => return charge_corporate_account(env, company, renter, amount),
+        PaymentSource::Sponsor(payer) => {
+            // The allowance only covers renters the sponsor approved, otherwise
+            // anyone could name the sponsor and spend it
+            if !is_sponsored_renter(env, payer, renter) {
+                return Err(Error::Unauthorized);
+            }
+            token_transfer_from(env, payer, &contract_address, &amount)?
+        }
+        PaymentSource::Renter => token_transfer(env, renter, &contract_address, &amount),
+    }
+
+    let contract_balance = read_contract_balance(env)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    write_contract_balance(env, &contract_balance);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events::{self, credit::CreditEvent},
+    storage::{
+        credit::{read_credit, read_credit_balances, write_credit, write_credit_balances},
+        types::errors::Error,
+    },
+};
+
+// Adds to the renter's credit. The caller moves the tokens and ContractBalance.
+pub fn add_credit(env: &Env, renter: &Address, amount: i128) -> Result<i128, Error> {
+    let credit = read_credit(env, renter)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    let credit_balances = read_credit_balances(env)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+
+    write_credit(env, renter, &credit);
+    write_credit_balances(env, &credit_balances);
+    Ok(credit)
+}
+
+// Takes from the renter's credit. The caller moves the tokens and ContractBalance.
+pub fn use_credit(env: &Env, renter: &Address, amount: i128) -> Result<i128, Error> {
+    let credit = read_credit(env, renter);
+    if credit < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let credit_balances = read_credit_balances(env)
+        .checked_sub(amount)
+        .ok_or(Error::Underflow)?;
+
+    write_credit(env, renter, &(credit - amount));
+    write_credit_balances(env, &credit_balances);
+    Ok(credit - amount)
+}
+
+// Pays a rental out of credit the contract already holds
+pub fn charge_credit(env: &Env, renter: &Address, amount: i128) -> Result<(), Error> {
+    let balance_after = use_credit(env, renter, amount)?;
+
+    events::credit::credit_charged(env, CreditEvent {
+        renter: renter.clone(),
+        amount,
+        balance_after,
+    });
+    Ok(())
+}
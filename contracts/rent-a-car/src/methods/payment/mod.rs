@@ -0,0 +1,4 @@
+mod collect;
+mod credit;
+pub use collect::collect_payment;
+pub use credit::{add_credit, use_credit};
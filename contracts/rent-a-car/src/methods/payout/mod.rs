@@ -0,0 +1,2 @@
+mod pay_owner;
+pub use pay_owner::pay_owner;
@@ -0,0 +1,44 @@
+use soroban_sdk::{Address, Env};
+use crate::{
+    events::{self, payout_owner::PayoutEvent},
+    methods::{car_token::car_payout_address, token::token::token_transfer},
+    storage::{
+        car::{read_car, write_car},
+        contract_balance::{read_contract_balance, write_contract_balance},
+        types::errors::Error,
+    },
+};
+
+// Shared by every payout entry point. Callers are responsible for auth and
+// for checking that the car exists.
+pub fn pay_owner(env: &Env, owner: &Address, amount: i128) -> Result<(), Error> {
+    let mut car = read_car(env, owner);
+
+    // Settled earnings can be withdrawn even while the car is rented
+    if amount > car.available_to_withdraw {
+        return Err(Error::InsufficientBalance);
+    }
+    let mut contract_balance = read_contract_balance(env);
+    let available_before = car.available_to_withdraw;
+
+    car.available_to_withdraw = car.available_to_withdraw
+        .checked_sub(amount)
+        .ok_or(Error::Underflow)?;
+    contract_balance = contract_balance
+        .checked_sub(amount)
+        .ok_or(Error::Underflow)?;
+
+    write_car(env, owner, &car);
+    write_contract_balance(env, &contract_balance);
+
+    let beneficiary = car_payout_address(env, owner);
+    token_transfer(env, &env.current_contract_address(), &beneficiary, &amount);
+    events::payout_owner::payout_owner(env, PayoutEvent {
+        owner: owner.clone(),
+        beneficiary,
+        amount,
+        available_before,
+        available_after: car.available_to_withdraw,
+    });
+    Ok(())
+}
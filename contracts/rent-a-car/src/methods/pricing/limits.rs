@@ -0,0 +1,27 @@
+use crate::{
+    methods::pricing::SECONDS_PER_DAY,
+    storage::{structs::car::Car, types::{errors::Error, rental_unit::RentalUnit}},
+};
+
+pub fn validate_rental_limits(min_days: u32, max_days: u32) -> Result<(), Error> {
+    if min_days == 0 || min_days > max_days {
+        return Err(Error::InvalidRentalLimits);
+    }
+    Ok(())
+}
+
+// Shared by every entry point that sets or changes a rental's length. The
+// minimum only applies to daily rentals, hourly ones are short by design.
+pub fn check_rental_duration(car: &Car, unit: RentalUnit, units: u32) -> Result<(), Error> {
+    if unit == RentalUnit::Day && units < car.min_days {
+        return Err(Error::RentalTooShort);
+    }
+
+    let duration = (units as u64)
+        .checked_mul(unit.seconds())
+        .ok_or(Error::RentalTooLong)?;
+    if duration > (car.max_days as u64) * SECONDS_PER_DAY {
+        return Err(Error::RentalTooLong);
+    }
+    Ok(())
+}
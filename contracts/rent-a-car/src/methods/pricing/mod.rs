@@ -0,0 +1,8 @@
+mod limits;
+mod quote;
+mod rules;
+mod tax;
+pub use limits::{check_rental_duration, validate_rental_limits};
+pub use quote::{compute_quote, SECONDS_PER_DAY, SECONDS_PER_HOUR};
+pub use rules::{apply_pricing_rules, validate_pricing_rules};
+pub use tax::{compute_tax, validate_tax_rate};
@@ -0,0 +1,74 @@
+use soroban_sdk::Vec;
+
+use crate::{
+    methods::pricing::{apply_pricing_rules, check_rental_duration, compute_tax},
+    storage::{
+        structs::{car::Car, quote::Quote},
+        types::{errors::Error, pricing_rule::PricingRule, rental_unit::RentalUnit},
+    },
+};
+
+pub const SECONDS_PER_HOUR: u64 = 3_600;
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+// Single source of truth for what a rental costs: `rental` charges exactly
+// what this returns and `quote_rental` exposes it to the UI.
+pub fn compute_quote(
+    car: &Car,
+    rules: &Vec<PricingRule>,
+    tax_rate_bps: u32,
+    unit: RentalUnit,
+    units: u32,
+    start: u64,
+) -> Result<Quote, Error> {
+    if units == 0 {
+        return Err(Error::RentalDurationCannotBeZero);
+    }
+    check_rental_duration(car, unit, units)?;
+
+    let price_per_unit = match unit {
+        RentalUnit::Day => car.price_per_day,
+        RentalUnit::Hour if car.price_per_hour > 0 => car.price_per_hour,
+        RentalUnit::Hour => return Err(Error::HourlyRentalsNotOffered),
+    };
+
+    let end = (units as u64)
+        .checked_mul(unit.seconds())
+        .and_then(|duration| start.checked_add(duration))
+        .ok_or(Error::InvalidTimeRange)?;
+
+    let list_rent = price_per_unit
+        .checked_mul(units as i128)
+        .ok_or(Error::Overflow)?;
+    let base_rent = apply_pricing_rules(rules, price_per_unit, unit, units, start)?;
+
+    // Fixed commission amount from car
+    let commission = car.commission_amount;
+    let deposit = 0;
+    let discount = 0;
+    // Tax is on the full price; a promo discount later comes out of the
+    // commission without changing what was withheld
+    let tax = base_rent
+        .checked_add(commission)
+        .ok_or(Error::Overflow)
+        .and_then(|taxable| compute_tax(taxable, tax_rate_bps))?;
+
+    let total = base_rent
+        .checked_add(commission)
+        .and_then(|total| total.checked_add(deposit))
+        .and_then(|total| total.checked_add(tax))
+        .and_then(|total| total.checked_sub(discount))
+        .ok_or(Error::Overflow)?;
+
+    Ok(Quote {
+        start,
+        end,
+        list_rent,
+        base_rent,
+        commission,
+        deposit,
+        discount,
+        tax,
+        total,
+    })
+}
@@ -0,0 +1,119 @@
+use soroban_sdk::Vec;
+
+use crate::{
+    methods::pricing::SECONDS_PER_DAY,
+    storage::types::{errors::Error, pricing_rule::PricingRule, rental_unit::RentalUnit},
+};
+
+pub const MAX_PRICING_RULES: u32 = 8;
+const BPS_DENOMINATOR: i128 = 10_000;
+const MAX_SURCHARGE_BPS: i32 = 10_000;
+
+pub fn validate_pricing_rules(rules: &Vec<PricingRule>) -> Result<(), Error> {
+    if rules.len() > MAX_PRICING_RULES {
+        return Err(Error::TooManyPricingRules);
+    }
+
+    for rule in rules.iter() {
+        let valid = match rule {
+            PricingRule::Weekend(bps) => is_valid_adjustment(bps),
+            PricingRule::Season(start, end, bps) => start < end && is_valid_adjustment(bps),
+            PricingRule::LongRental(min_days, bps) => min_days > 0 && bps > 0 && (bps as i128) < BPS_DENOMINATOR,
+        };
+        if !valid {
+            return Err(Error::InvalidPricingRule);
+        }
+    }
+    Ok(())
+}
+
+// A unit can never become free or negative, and surcharges are capped at 100%
+fn is_valid_adjustment(bps: i32) -> bool {
+    (bps as i128) > -BPS_DENOMINATOR && bps <= MAX_SURCHARGE_BPS
+}
+
+// Rent for `units` starting at `start` once the car's rules are applied.
+// Each unit is priced by the time it starts. Precedence when rules overlap:
+// a seasonal window replaces the weekend adjustment for the units it covers,
+// and among overlapping seasons the one listed last wins. Only the largest
+// qualifying long-rental tier applies, on top of the per-unit prices.
+pub fn apply_pricing_rules(
+    rules: &Vec<PricingRule>,
+    price_per_unit: i128,
+    unit: RentalUnit,
+    units: u32,
+    start: u64,
+) -> Result<i128, Error> {
+    let rental_days = (units as u64) * unit.seconds() / SECONDS_PER_DAY;
+
+    let mut weekend_bps: i32 = 0;
+    let mut seasons = [(0_u64, 0_u64, 0_i32); MAX_PRICING_RULES as usize];
+    let mut season_count = 0;
+    let mut tier_days: u32 = 0;
+    let mut tier_bps: u32 = 0;
+
+    for rule in rules.iter() {
+        match rule {
+            PricingRule::Weekend(bps) => weekend_bps = bps,
+            PricingRule::Season(window_start, window_end, bps) => {
+                seasons[season_count] = (window_start, window_end, bps);
+                season_count += 1;
+            }
+            PricingRule::LongRental(min_days, bps) => {
+                if rental_days >= min_days as u64 && min_days >= tier_days {
+                    tier_days = min_days;
+                    tier_bps = bps;
+                }
+            }
+        }
+    }
+
+    let mut rent: i128 = 0;
+    if weekend_bps == 0 && season_count == 0 {
+        rent = price_per_unit
+            .checked_mul(units as i128)
+            .ok_or(Error::Overflow)?;
+    } else {
+        for index in 0..units as u64 {
+            let unit_start = start
+                .checked_add(index * unit.seconds())
+                .ok_or(Error::InvalidTimeRange)?;
+
+            let mut bps = if is_weekend(unit_start) { weekend_bps } else { 0 };
+            if let Some(&(_, _, season_bps)) = seasons[..season_count]
+                .iter()
+                .rev()
+                .find(|(window_start, window_end, _)| unit_start >= *window_start && unit_start < *window_end)
+            {
+                bps = season_bps;
+            }
+
+            rent = rent
+                .checked_add(adjust(price_per_unit, bps as i128)?)
+                .ok_or(Error::Overflow)?;
+        }
+    }
+
+    if tier_bps > 0 {
+        let tier_discount = rent
+            .checked_mul(tier_bps as i128)
+            .map(|discount| discount / BPS_DENOMINATOR)
+            .ok_or(Error::Overflow)?;
+        rent -= tier_discount;
+    }
+
+    Ok(rent)
+}
+
+fn adjust(price: i128, bps: i128) -> Result<i128, Error> {
+    price
+        .checked_mul(BPS_DENOMINATOR + bps)
+        .map(|adjusted| adjusted / BPS_DENOMINATOR)
+        .ok_or(Error::Overflow)
+}
+
+// Day 0 of the Unix epoch was a Thursday
+fn is_weekend(timestamp: u64) -> bool {
+    let weekday = (timestamp / SECONDS_PER_DAY + 3) % 7; // 0 is Monday
+    weekday >= 5
+}
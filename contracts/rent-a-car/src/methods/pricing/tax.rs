@@ -0,0 +1,18 @@
+use crate::storage::types::errors::Error;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+pub fn validate_tax_rate(rate_bps: u32) -> Result<(), Error> {
+    if (rate_bps as i128) > BPS_DENOMINATOR {
+        return Err(Error::InvalidBasisPoints);
+    }
+    Ok(())
+}
+
+// Rounds down, so the renter is never charged more than the rate
+pub fn compute_tax(taxable: i128, rate_bps: u32) -> Result<i128, Error> {
+    taxable
+        .checked_mul(rate_bps as i128)
+        .map(|tax| tax / BPS_DENOMINATOR)
+        .ok_or(Error::Overflow)
+}
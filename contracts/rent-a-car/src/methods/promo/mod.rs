@@ -0,0 +1,2 @@
+mod redeem;
+pub use redeem::{redeem_promo_code, validate_promo_code};
@@ -0,0 +1,72 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::storage::{
+    promo_code::{read_promo_code, read_promo_redemptions, write_promo_code, write_promo_redemptions},
+    structs::{promo_code::PromoCode, quote::Quote},
+    types::{errors::Error, promo_discount::PromoDiscount},
+};
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+pub fn validate_promo_code(env: &Env, promo: &PromoCode) -> Result<(), Error> {
+    let valid_discount = match promo.discount {
+        PromoDiscount::Bps(bps) => bps > 0 && (bps as i128) <= BPS_DENOMINATOR,
+        PromoDiscount::Fixed(amount) => amount > 0,
+    };
+
+    if !valid_discount
+        || promo.max_uses == 0
+        || promo.expires_at <= env.ledger().timestamp()
+        || promo.per_renter_limit == Some(0)
+    {
+        return Err(Error::InvalidPromoCode);
+    }
+    Ok(())
+}
+
+// Applies the code to the quote and records the redemption. The discount is
+// capped at the commission so it is funded by the platform fee and the owner
+// still receives the full base rent. Returns the code hash and its total uses.
+pub fn redeem_promo_code(
+    env: &Env,
+    code: &Bytes,
+    renter: &Address,
+    quote: &mut Quote,
+) -> Result<(BytesN<32>, u32), Error> {
+    let code_hash: BytesN<32> = env.crypto().sha256(code).into();
+    let mut promo = read_promo_code(env, &code_hash).ok_or(Error::PromoCodeNotFound)?;
+
+    if env.ledger().timestamp() >= promo.expires_at {
+        return Err(Error::PromoCodeExpired);
+    }
+    if promo.uses >= promo.max_uses {
+        return Err(Error::PromoCodeExhausted);
+    }
+
+    let redemptions = read_promo_redemptions(env, &code_hash, renter);
+    if let Some(limit) = promo.per_renter_limit {
+        if redemptions >= limit {
+            return Err(Error::PromoCodeLimitReached);
+        }
+    }
+
+    let discount = match promo.discount {
+        PromoDiscount::Bps(bps) => quote.base_rent
+            .checked_mul(bps as i128)
+            .map(|discount| discount / BPS_DENOMINATOR)
+            .ok_or(Error::Overflow)?,
+        PromoDiscount::Fixed(amount) => amount,
+    };
+    let discount = discount.min(quote.commission);
+
+    quote.discount = discount;
+    quote.total = quote.total
+        .checked_sub(discount)
+        .ok_or(Error::Underflow)?;
+
+    promo.uses += 1;
+    write_promo_code(env, &code_hash, &promo);
+    write_promo_redemptions(env, &code_hash, renter, redemptions + 1);
+
+    Ok((code_hash, promo.uses))
+}
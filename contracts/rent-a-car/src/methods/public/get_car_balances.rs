@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::{
+    car::{read_car, has_car},
+    types::errors::Error,
+};
+
+// Returns (settled, pending) owner earnings for the car
+pub fn get_car_balances(env: &Env, owner: &Address) -> Result<(i128, i128), Error> {
+    if !has_car(env, owner) {
+        return Err(Error::CarNotFound);
+    }
+
+    let car = read_car(env, owner);
+    Ok((car.available_to_withdraw, car.pending_to_withdraw))
+}
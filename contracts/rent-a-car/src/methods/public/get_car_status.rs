@@ -9,7 +9,7 @@ pub fn get_car_status(env: &Env, owner: &Address) -> Result<CarStatus, Error> {
     if !has_car(env, owner) {
         return Err(Error::CarNotFound);
     }
-    let car: Car = read_car(env, &owner);
+    let car: Car = read_car(env, owner);
 
     Ok(car.car_status)
 }
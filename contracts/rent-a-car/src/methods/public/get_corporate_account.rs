@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, Env};
+use crate::{
+    methods::corporate::roll_period,
+    storage::{
+        corporate::{has_corporate_account, read_corporate_account},
+        structs::corporate_account::CorporateAccount,
+        types::errors::Error,
+    },
+};
+
+// Returns the account as of now, so a finished period reads as nothing spent
+pub fn get_corporate_account(env: &Env, company: &Address) -> Result<CorporateAccount, Error> {
+    if !has_corporate_account(env, company) {
+        return Err(Error::CorporateAccountNotFound);
+    }
+
+    let mut account = read_corporate_account(env, company);
+    roll_period(&mut account, env.ledger().timestamp());
+    Ok(account)
+}
+
+// Spent in the current calendar month and what is left of the monthly limit
+pub fn get_corporate_spend(env: &Env, company: &Address) -> Result<(i128, i128), Error> {
+    let account = get_corporate_account(env, company)?;
+    let remaining = account.monthly_limit
+        .checked_sub(account.period_spent)
+        .ok_or(Error::Underflow)?;
+
+    // A lowered limit can leave the period already above it
+    Ok((account.period_spent, remaining.max(0)))
+}
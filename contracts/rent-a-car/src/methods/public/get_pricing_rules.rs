@@ -0,0 +1,14 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::storage::{
+    car::has_car,
+    pricing_rules::read_pricing_rules,
+    types::{errors::Error, pricing_rule::PricingRule},
+};
+
+pub fn get_pricing_rules(env: &Env, owner: &Address) -> Result<Vec<PricingRule>, Error> {
+    if !has_car(env, owner) {
+        return Err(Error::CarNotFound);
+    }
+
+    Ok(read_pricing_rules(env, owner))
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::{
+    car::{read_car, has_car},
+    types::errors::Error,
+};
+
+pub fn get_rental_limits(env: &Env, owner: &Address) -> Result<(u32, u32), Error> {
+    if !has_car(env, owner) {
+        return Err(Error::CarNotFound);
+    }
+
+    let car = read_car(env, owner);
+    Ok((car.min_days, car.max_days))
+}
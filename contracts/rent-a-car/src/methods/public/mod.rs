@@ -1,6 +1,16 @@
 mod get_car_status;
 mod get_car_info;
+mod get_car_balances;
 mod has_rental;
+mod quote_rental;
+mod get_rental_limits;
+mod get_pricing_rules;
+mod get_corporate_account;
 pub use get_car_status::get_car_status;
 pub use get_car_info::get_car_info;
+pub use get_car_balances::get_car_balances;
 pub use has_rental::check_has_rental;
+pub use quote_rental::quote_rental;
+pub use get_rental_limits::get_rental_limits;
+pub use get_pricing_rules::get_pricing_rules;
+pub use get_corporate_account::{get_corporate_account, get_corporate_spend};
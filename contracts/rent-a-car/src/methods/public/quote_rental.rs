@@ -0,0 +1,24 @@
+use soroban_sdk::{Address, Env};
+use crate::{
+    methods::pricing::compute_quote,
+    storage::{
+        car::{has_car, read_car},
+        pricing_rules::read_pricing_rules,
+        structs::quote::Quote,
+        tax::read_tax_rate,
+        types::{errors::Error, rental_unit::RentalUnit},
+    },
+};
+
+pub fn quote_rental(env: &Env, owner: &Address, unit: RentalUnit, units: u32, start: u64) -> Result<Quote, Error> {
+    if !has_car(env, owner) {
+        return Err(Error::CarNotFound);
+    }
+    // A rental can only start from now on
+    if start < env.ledger().timestamp() {
+        return Err(Error::RentalExpired);
+    }
+
+    let car = read_car(env, owner);
+    compute_quote(&car, &read_pricing_rules(env, owner), read_tax_rate(env), unit, units, start)
+}
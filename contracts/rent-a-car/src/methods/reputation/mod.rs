@@ -0,0 +1,2 @@
+mod rating;
+pub use rating::{add_rating, average_score, record_completed_rental, record_dispute_lost, MAX_RATING};
@@ -0,0 +1,60 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::{
+    reputation::{read_reputation, write_reputation, write_rating_status},
+    structs::reputation::{RatingStatus, Reputation},
+    types::errors::Error,
+};
+
+pub const MIN_RATING: u32 = 1;
+pub const MAX_RATING: u32 = 5;
+
+// Opens a rating window for both sides and records late returns
+pub fn record_completed_rental(env: &Env, renter: &Address, owner: &Address, late: bool) -> Result<(), Error> {
+    write_rating_status(env, renter, owner, &RatingStatus {
+        owner_rated: false,
+        renter_rated: false,
+    });
+
+    if late {
+        let mut reputation = read_reputation(env, renter);
+        reputation.late_returns = reputation.late_returns
+            .checked_add(1)
+            .ok_or(Error::Overflow)?;
+        write_reputation(env, renter, &reputation);
+    }
+    Ok(())
+}
+
+// A paid damage claim is a dispute decided against the renter
+pub fn record_dispute_lost(env: &Env, renter: &Address) -> Result<(), Error> {
+    let mut reputation = read_reputation(env, renter);
+    reputation.disputes_lost = reputation.disputes_lost
+        .checked_add(1)
+        .ok_or(Error::Overflow)?;
+    write_reputation(env, renter, &reputation);
+    Ok(())
+}
+
+pub fn add_rating(env: &Env, rated: &Address, score: u32) -> Result<Reputation, Error> {
+    if !(MIN_RATING..=MAX_RATING).contains(&score) {
+        return Err(Error::InvalidRating);
+    }
+
+    let mut reputation = read_reputation(env, rated);
+    reputation.rating_count = reputation.rating_count
+        .checked_add(1)
+        .ok_or(Error::Overflow)?;
+    reputation.rating_sum = reputation.rating_sum
+        .checked_add(score)
+        .ok_or(Error::Overflow)?;
+    write_reputation(env, rated, &reputation);
+    Ok(reputation)
+}
+
+// Average rating scaled by 100 (e.g. 450 is 4.5 stars), None when unrated
+pub fn average_score(reputation: &Reputation) -> Option<u32> {
+    if reputation.rating_count == 0 {
+        return None;
+    }
+    Some(reputation.rating_sum * 100 / reputation.rating_count)
+}
@@ -0,0 +1,2 @@
+mod split;
+pub use split::{split_settled, validate_revenue_shares};
@@ -0,0 +1,86 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    events::{self, revenue::RevenueSplitEvent},
+    storage::{
+        revenue_share::{read_revenue_shares, read_share_balance, read_share_balances, write_share_balance, write_share_balances},
+        structs::{car::Car, revenue_share::RevenueShare},
+        types::errors::Error,
+    },
+};
+
+pub const MAX_REVENUE_SHARES: u32 = 10;
+const BPS_DENOMINATOR: i128 = 10_000;
+
+// An empty list is valid and means the owner keeps all of the income.
+// Anything else needs distinct beneficiaries with shares adding up to 10,000.
+pub fn validate_revenue_shares(shares: &Vec<RevenueShare>) -> Result<(), Error> {
+    if shares.is_empty() {
+        return Ok(());
+    }
+    if shares.len() > MAX_REVENUE_SHARES {
+        return Err(Error::InvalidBasisPoints);
+    }
+
+    let mut total: i128 = 0;
+    for (i, share) in shares.iter().enumerate() {
+        if share.bps == 0 {
+            return Err(Error::InvalidBasisPoints);
+        }
+        if shares.iter().skip(i + 1).any(|other| other.beneficiary == share.beneficiary) {
+            return Err(Error::InvalidBasisPoints);
+        }
+        total += share.bps as i128;
+    }
+
+    if total != BPS_DENOMINATOR {
+        return Err(Error::InvalidBasisPoints);
+    }
+    Ok(())
+}
+
+// Moves an amount just credited to the car's balance, settled rent or an
+// insurance claim, out to the car's beneficiaries. Rounding dust goes to the first beneficiary. Cars without
+// shares keep paying their owner as before.
+pub fn split_settled(env: &Env, owner: &Address, car: &mut Car, amount: i128) -> Result<(), Error> {
+    let shares = read_revenue_shares(env, owner);
+    if shares.is_empty() || amount <= 0 {
+        return Ok(());
+    }
+
+    car.available_to_withdraw = car.available_to_withdraw
+        .checked_sub(amount)
+        .ok_or(Error::Underflow)?;
+
+    let mut remaining = amount;
+    for share in shares.iter().skip(1) {
+        let part = amount
+            .checked_mul(share.bps as i128)
+            .map(|part| part / BPS_DENOMINATOR)
+            .ok_or(Error::Overflow)?;
+        credit_share(env, owner, &share.beneficiary, part)?;
+        remaining -= part;
+    }
+    let first = shares.get_unchecked(0);
+    credit_share(env, owner, &first.beneficiary, remaining)?;
+
+    let share_balances = read_share_balances(env)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    write_share_balances(env, &share_balances);
+
+    events::revenue::revenue_split(env, RevenueSplitEvent {
+        owner: owner.clone(),
+        amount,
+        shares,
+    });
+    Ok(())
+}
+
+fn credit_share(env: &Env, owner: &Address, beneficiary: &Address, amount: i128) -> Result<(), Error> {
+    let balance = read_share_balance(env, owner, beneficiary)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    write_share_balance(env, owner, beneficiary, &balance);
+    Ok(())
+}
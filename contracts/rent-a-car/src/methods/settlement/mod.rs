@@ -0,0 +1,2 @@
+mod settle;
+pub use settle::settle_accrued;
@@ -0,0 +1,43 @@
+use crate::storage::{
+    structs::{car::Car, rental::Rental},
+    types::errors::Error,
+};
+
+// Portion of the rental amount earned by the owner for the whole units (hours
+// or days) elapsed since the rental started. Partial units stay in escrow.
+pub fn accrued_amount(rental: &Rental, now: u64) -> Result<i128, Error> {
+    let elapsed_units = now.saturating_sub(rental.start_time) / rental.unit.seconds();
+    let total_units = rental.units as u64;
+
+    if elapsed_units >= total_units {
+        return Ok(rental.amount);
+    }
+
+    rental.amount
+        .checked_mul(elapsed_units as i128)
+        .and_then(|accrued| accrued.checked_div(total_units as i128))
+        .ok_or(Error::Overflow)
+}
+
+// Moves the accrued but not yet settled part of the rental from the car's
+// pending balance to its settled balance. Returns the amount moved.
+pub fn settle_accrued(car: &mut Car, rental: &mut Rental, now: u64) -> Result<i128, Error> {
+    let accrued = accrued_amount(rental, now)?;
+    let to_settle = accrued
+        .checked_sub(rental.settled_amount)
+        .ok_or(Error::Underflow)?;
+
+    if to_settle <= 0 {
+        return Ok(0);
+    }
+
+    car.pending_to_withdraw = car.pending_to_withdraw
+        .checked_sub(to_settle)
+        .ok_or(Error::Underflow)?;
+    car.available_to_withdraw = car.available_to_withdraw
+        .checked_add(to_settle)
+        .ok_or(Error::Overflow)?;
+    rental.settled_amount = accrued;
+
+    Ok(to_settle)
+}
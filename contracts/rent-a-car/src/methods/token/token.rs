@@ -3,10 +3,25 @@ use soroban_sdk::{
     Address, Env,
 };
 
-use crate::storage::token::read_token;
+use crate::storage::{token::read_token, types::errors::Error};
 
 pub fn token_transfer(env: &Env, from: &Address, to: &Address, amount: &i128) {
     let token_address = read_token(env);
     let token = token::TokenClient::new(env, &token_address);
     token.transfer(from, to, amount);
-}
\ No newline at end of file
+}
+
+// Pulls funds the `from` address approved for this contract, so `from` does
+// not have to sign the invocation.
+pub fn token_transfer_from(env: &Env, from: &Address, to: &Address, amount: &i128) -> Result<(), Error> {
+    let token_address = read_token(env);
+    let token = token::TokenClient::new(env, &token_address);
+    let spender = env.current_contract_address();
+
+    if token.allowance(from, &spender) < *amount {
+        return Err(Error::InsufficientAllowance);
+    }
+
+    token.transfer_from(&spender, from, to, amount);
+    Ok(())
+}
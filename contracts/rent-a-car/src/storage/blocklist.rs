@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn is_blocked_by_owner(env: &Env, owner: &Address, renter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::OwnerBlocklist(owner.clone(), renter.clone()))
+}
+
+pub(crate) fn write_blocked_by_owner(env: &Env, owner: &Address, renter: &Address, blocked: bool) {
+    let key = DataKey::OwnerBlocklist(owner.clone(), renter.clone());
+
+    if blocked {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub(crate) fn is_blocked_globally(env: &Env, renter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::GlobalBlocklist(renter.clone()))
+}
+
+pub(crate) fn write_blocked_globally(env: &Env, renter: &Address, blocked: bool) {
+    let key = DataKey::GlobalBlocklist(renter.clone());
+
+    if blocked {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
@@ -1,21 +1,65 @@
-    
-
 use soroban_sdk::{Address, Env};
 
 use crate::storage::{structs::car::Car, types::storage::DataKey};
 
+// Cars live in persistent storage: instance storage is loaded on every call,
+// so it must not grow with the fleet.
 pub(crate) fn has_car(env: &Env, owner: &Address) -> bool {
-    env.storage().instance().has(&DataKey::Car(owner.clone()))
+    env.storage().persistent().has(&DataKey::Car(owner.clone()))
 }
 
 pub(crate) fn read_car(env: &Env, owner: &Address) -> Car {
-    env.storage().instance().get(&DataKey::Car(owner.clone())).unwrap()
+    env.storage().persistent().get(&DataKey::Car(owner.clone())).unwrap()
 }
 
+// Every change to a car's balances goes through here, which keeps the fleet
+// wide totals in step without walking the cars.
 pub(crate) fn write_car(env: &Env, owner: &Address, car: &Car) {
-    env.storage().instance().set(&DataKey::Car(owner.clone()), car);
+    let key = DataKey::Car(owner.clone());
+    let (available_before, pending_before) = match env.storage().persistent().get::<_, Car>(&key) {
+        Some(previous) => (previous.available_to_withdraw, previous.pending_to_withdraw),
+        None => (0, 0),
+    };
+
+    adjust_balances(
+        env,
+        car.available_to_withdraw - available_before,
+        car.pending_to_withdraw - pending_before,
+    );
+    env.storage().persistent().set(&key, car);
 }
 
 pub(crate) fn remove_car(env: &Env, owner: &Address) {
-    env.storage().instance().remove(&DataKey::Car(owner.clone()));
-}
\ No newline at end of file
+    let key = DataKey::Car(owner.clone());
+    if let Some(car) = env.storage().persistent().get::<_, Car>(&key) {
+        adjust_balances(env, -car.available_to_withdraw, -car.pending_to_withdraw);
+    }
+    env.storage().persistent().remove(&key);
+}
+
+// Settled earnings across all cars
+pub(crate) fn read_owner_balances(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerBalances)
+        .unwrap_or(0)
+}
+
+// Rental escrow across all cars, not yet settled to owners
+pub(crate) fn read_pending_balances(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingBalances)
+        .unwrap_or(0)
+}
+
+fn adjust_balances(env: &Env, available_delta: i128, pending_delta: i128) {
+    if available_delta != 0 {
+        let total = read_owner_balances(env) + available_delta;
+        env.storage().persistent().set(&DataKey::OwnerBalances, &total);
+    }
+    if pending_delta != 0 {
+        let total = read_pending_balances(env) + pending_delta;
+        env.storage().persistent().set(&DataKey::PendingBalances, &total);
+    }
+}
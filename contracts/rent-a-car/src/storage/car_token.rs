@@ -0,0 +1,74 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+// Every listed car is an NFT. Ids are never reused, so a token burned on
+// delisting can't be confused with a later listing.
+pub(crate) fn next_car_token_id(env: &Env) -> u32 {
+    let id = env.storage()
+        .persistent()
+        .get(&DataKey::CarTokenCount)
+        .unwrap_or(0_u32) + 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarTokenCount, &id);
+    id
+}
+
+pub(crate) fn has_car_token(env: &Env, token_id: u32) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::CarTokenHolder(token_id))
+}
+
+pub(crate) fn read_car_token_holder(env: &Env, token_id: u32) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarTokenHolder(token_id))
+        .unwrap()
+}
+
+// The address the car is listed under, which every car entry point takes as `owner`
+pub(crate) fn read_car_token_listing(env: &Env, token_id: u32) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarTokenListing(token_id))
+        .unwrap()
+}
+
+pub(crate) fn write_car_token(env: &Env, token_id: u32, holder: &Address, listing: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarTokenHolder(token_id), holder);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarTokenListing(token_id), listing);
+}
+
+pub(crate) fn remove_car_token(env: &Env, token_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CarTokenHolder(token_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CarTokenListing(token_id));
+}
+
+pub(crate) fn read_car_token_balance(env: &Env, account: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarTokenBalance(account.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_car_token_balance(env: &Env, account: &Address, balance: u32) {
+    if balance == 0 {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CarTokenBalance(account.clone()));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CarTokenBalance(account.clone()), &balance);
+    }
+}
@@ -0,0 +1,52 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::corporate_account::CorporateAccount, types::storage::DataKey};
+
+pub(crate) fn has_corporate_account(env: &Env, company: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::CorporateAccount(company.clone()))
+}
+
+pub(crate) fn read_corporate_account(env: &Env, company: &Address) -> CorporateAccount {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CorporateAccount(company.clone()))
+        .unwrap()
+}
+
+pub(crate) fn write_corporate_account(env: &Env, company: &Address, account: &CorporateAccount) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CorporateAccount(company.clone()), account);
+}
+
+pub(crate) fn is_corporate_driver(env: &Env, company: &Address, driver: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::CorporateDriver(company.clone(), driver.clone()))
+}
+
+pub(crate) fn write_corporate_driver(env: &Env, company: &Address, driver: &Address, authorized: bool) {
+    let key = DataKey::CorporateDriver(company.clone(), driver.clone());
+
+    if authorized {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// Sum of every corporate balance, kept so reconcile does not have to walk accounts
+pub(crate) fn read_corporate_balances(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CorporateBalances)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_corporate_balances(env: &Env, amount: &i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CorporateBalances, amount);
+}
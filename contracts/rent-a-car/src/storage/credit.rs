@@ -0,0 +1,34 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn read_credit(env: &Env, renter: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Credit(renter.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_credit(env: &Env, renter: &Address, amount: &i128) {
+    let key = DataKey::Credit(renter.clone());
+
+    if *amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, amount);
+    }
+}
+
+// Sum of every renter's credit, kept so reconcile does not have to walk renters
+pub(crate) fn read_credit_balances(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreditBalances)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_credit_balances(env: &Env, amount: &i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreditBalances, amount);
+}
@@ -0,0 +1,62 @@
+use soroban_sdk::Env;
+
+use crate::storage::{
+    structs::insurance::{Coverage, InsurancePlan, InsurancePool},
+    types::storage::DataKey,
+};
+
+pub(crate) fn read_insurance_plan(env: &Env) -> Option<InsurancePlan> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsurancePlan)
+}
+
+pub(crate) fn write_insurance_plan(env: &Env, plan: &InsurancePlan) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InsurancePlan, plan);
+}
+
+pub(crate) fn remove_insurance_plan(env: &Env) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::InsurancePlan);
+}
+
+pub(crate) fn read_insurance_pool(env: &Env) -> InsurancePool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsurancePool)
+        .unwrap_or(InsurancePool { balance: 0, premiums_collected: 0, claims_paid: 0 })
+}
+
+pub(crate) fn write_insurance_pool(env: &Env, pool: &InsurancePool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InsurancePool, pool);
+}
+
+// Each insured rental gets its own id, so later rentals of the same car by
+// the same renter never touch coverage that can still be claimed
+pub(crate) fn next_coverage_id(env: &Env) -> u64 {
+    let id = env.storage()
+        .persistent()
+        .get(&DataKey::CoverageCount)
+        .unwrap_or(0_u64) + 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CoverageCount, &id);
+    id
+}
+
+pub(crate) fn read_coverage(env: &Env, coverage_id: u64) -> Option<Coverage> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coverage(coverage_id))
+}
+
+pub(crate) fn write_coverage(env: &Env, coverage_id: u64, coverage: &Coverage) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Coverage(coverage_id), coverage);
+}
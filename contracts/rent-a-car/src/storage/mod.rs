@@ -6,4 +6,17 @@ pub mod structs;
 pub mod types;
 pub mod contract_balance;
 pub mod admin_fee;
-pub mod admin_fees_balance;
\ No newline at end of file
+pub mod admin_fees_balance;
+pub mod payout_address;
+pub mod verification;
+pub mod reputation;
+pub mod blocklist;
+pub mod pricing_rules;
+pub mod promo_code;
+pub mod corporate;
+pub mod credit;
+pub mod insurance;
+pub mod tax;
+pub mod revenue_share;
+pub mod sponsor;
+pub mod car_token;
\ No newline at end of file
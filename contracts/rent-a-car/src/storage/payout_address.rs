@@ -0,0 +1,29 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+// Where the owner's payouts are sent; defaults to the owner itself
+pub(crate) fn read_payout_address(env: &Env, owner: &Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PayoutAddress(owner.clone()))
+        .unwrap_or(owner.clone())
+}
+
+pub(crate) fn has_payout_address(env: &Env, owner: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::PayoutAddress(owner.clone()))
+}
+
+pub(crate) fn write_payout_address(env: &Env, owner: &Address, beneficiary: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PayoutAddress(owner.clone()), beneficiary);
+}
+
+pub(crate) fn remove_payout_address(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PayoutAddress(owner.clone()));
+}
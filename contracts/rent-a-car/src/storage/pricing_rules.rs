@@ -0,0 +1,26 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::types::{pricing_rule::PricingRule, storage::DataKey};
+
+pub(crate) fn read_pricing_rules(env: &Env, owner: &Address) -> Vec<PricingRule> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PricingRules(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_pricing_rules(env: &Env, owner: &Address, rules: &Vec<PricingRule>) {
+    let key = DataKey::PricingRules(owner.clone());
+
+    if rules.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, rules);
+    }
+}
+
+pub(crate) fn remove_pricing_rules(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PricingRules(owner.clone()));
+}
@@ -0,0 +1,40 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::storage::{structs::promo_code::PromoCode, types::storage::DataKey};
+
+pub(crate) fn has_promo_code(env: &Env, code_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::PromoCode(code_hash.clone()))
+}
+
+pub(crate) fn read_promo_code(env: &Env, code_hash: &BytesN<32>) -> Option<PromoCode> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PromoCode(code_hash.clone()))
+}
+
+pub(crate) fn write_promo_code(env: &Env, code_hash: &BytesN<32>, promo: &PromoCode) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoCode(code_hash.clone()), promo);
+}
+
+pub(crate) fn remove_promo_code(env: &Env, code_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PromoCode(code_hash.clone()));
+}
+
+pub(crate) fn read_promo_redemptions(env: &Env, code_hash: &BytesN<32>, renter: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PromoRedemptions(code_hash.clone(), renter.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_promo_redemptions(env: &Env, code_hash: &BytesN<32>, renter: &Address, redemptions: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoRedemptions(code_hash.clone(), renter.clone()), &redemptions);
+}
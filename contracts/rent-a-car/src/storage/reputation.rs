@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::reputation::{RatingStatus, Reputation},
+    types::storage::DataKey,
+};
+
+pub(crate) fn read_reputation(env: &Env, address: &Address) -> Reputation {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Reputation(address.clone()))
+        .unwrap_or_default()
+}
+
+pub(crate) fn write_reputation(env: &Env, address: &Address, reputation: &Reputation) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Reputation(address.clone()), reputation);
+}
+
+pub(crate) fn read_rating_status(env: &Env, renter: &Address, car_owner: &Address) -> Option<RatingStatus> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RatingStatus(renter.clone(), car_owner.clone()))
+}
+
+pub(crate) fn write_rating_status(env: &Env, renter: &Address, car_owner: &Address, status: &RatingStatus) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RatingStatus(renter.clone(), car_owner.clone()), status);
+}
@@ -0,0 +1,57 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{structs::revenue_share::RevenueShare, types::storage::DataKey};
+
+pub(crate) fn read_revenue_shares(env: &Env, owner: &Address) -> Vec<RevenueShare> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RevenueShares(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_revenue_shares(env: &Env, owner: &Address, shares: &Vec<RevenueShare>) {
+    let key = DataKey::RevenueShares(owner.clone());
+
+    if shares.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, shares);
+    }
+}
+
+pub(crate) fn remove_revenue_shares(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RevenueShares(owner.clone()));
+}
+
+pub(crate) fn read_share_balance(env: &Env, owner: &Address, beneficiary: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ShareBalance(owner.clone(), beneficiary.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_share_balance(env: &Env, owner: &Address, beneficiary: &Address, amount: &i128) {
+    let key = DataKey::ShareBalance(owner.clone(), beneficiary.clone());
+
+    if *amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, amount);
+    }
+}
+
+// Sum of every beneficiary's balance, kept so reconcile does not have to walk them
+pub(crate) fn read_share_balances(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ShareBalances)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_share_balances(env: &Env, amount: &i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ShareBalances, amount);
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn is_sponsored_renter(env: &Env, sponsor: &Address, renter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::SponsoredRenter(sponsor.clone(), renter.clone()))
+}
+
+pub(crate) fn write_sponsored_renter(env: &Env, sponsor: &Address, renter: &Address, approved: bool) {
+    let key = DataKey::SponsoredRenter(sponsor.clone(), renter.clone());
+
+    if approved {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
@@ -1,12 +1,20 @@
-use soroban_sdk::{contracttype};
+use soroban_sdk::{contracttype, Address};
 
 use crate::storage::types::car_status::CarStatus;
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Car {
+    pub token_id: u32, // NFT whose holder controls the listing
     pub price_per_day: i128,
+    pub price_per_hour: i128, // 0 when the owner does not offer hourly rentals
     pub car_status: CarStatus,
-    pub available_to_withdraw: i128,
+    pub available_to_withdraw: i128, // Settled earnings, withdrawable at any time
+    pub pending_to_withdraw: i128,   // Earnings of the current rental, settled on return
     pub commission_amount: i128, // Fixed commission amount in stroops (per rental)
+    pub current_renter: Option<Address>,
+    pub verified_renters_only: bool,
+    pub min_renter_score: u32, // Average rating x100 required to rent, 0 disables it
+    pub min_days: u32,
+    pub max_days: u32,
 }
\ No newline at end of file
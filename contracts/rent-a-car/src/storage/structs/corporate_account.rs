@@ -0,0 +1,10 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CorporateAccount {
+    pub monthly_limit: i128,
+    pub balance: i128,      // Prepaid funds held by the contract
+    pub period: u64,        // Calendar month `period_spent` belongs to, see `month_index`
+    pub period_spent: i128,
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct InsurancePlan {
+    pub premium_per_day: i128, // Charged per started day of the rental
+    pub coverage_cap: i128,    // Most a single covered rental can claim
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct InsurancePool {
+    pub balance: i128, // Premiums held by the contract and not yet paid out
+    pub premiums_collected: i128,
+    pub claims_paid: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Coverage {
+    pub owner: Address,
+    pub token_id: u32,   // The listing's NFT when the rental was insured
+    pub holder: Address, // Who held it then, and is owed the claim
+    pub renter: Address,
+    pub premium: i128,
+    pub coverage_cap: i128, // Cap of the plan at purchase time
+    pub claimed: i128,
+}
@@ -1,2 +1,11 @@
 pub mod car;
 pub mod rental;
+pub mod quote;
+pub mod reconcile_report;
+pub mod reputation;
+pub mod promo_code;
+pub mod corporate_account;
+
+pub mod insurance;
+pub mod tax_config;
+pub mod revenue_share;
\ No newline at end of file
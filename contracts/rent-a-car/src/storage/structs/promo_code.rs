@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype};
+
+use crate::storage::types::promo_discount::PromoDiscount;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PromoCode {
+    pub discount: PromoDiscount,
+    pub max_uses: u32,
+    pub uses: u32,
+    pub expires_at: u64,
+    pub per_renter_limit: Option<u32>,
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Quote {
+    pub start: u64,
+    pub end: u64,
+    pub list_rent: i128,  // Flat unit price times units, before the car's pricing rules
+    pub base_rent: i128,  // Goes to the owner
+    pub commission: i128, // Goes to the admin fees balance
+    pub deposit: i128,
+    pub discount: i128,
+    pub tax: i128,        // Withheld for the tax collector
+    pub total: i128,      // Amount transferred from the renter
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReconcileReport {
+    pub tracked_balance: i128,    // Stored ContractBalance
+    pub token_balance: i128,      // Actual token balance of the contract
+    pub owner_balances: i128,     // Settled earnings across all cars
+    pub deposits: i128,           // Rental escrow not yet settled to owners
+    pub admin_fees_balance: i128,
+    pub corporate_balances: i128, // Prepaid corporate funds not yet spent
+    pub credit_balances: i128,    // Prepaid renter credit not yet spent
+    pub insurance_pool: i128,     // Premiums not yet paid out as claims
+    pub tax_balance: i128,        // Tax withheld and not yet remitted
+    pub share_balances: i128,     // Co-owner income not yet withdrawn
+    pub liabilities: i128,        // Sum of every bucket above
+    pub surplus: i128,            // token_balance - liabilities
+    pub is_consistent: bool,      // tracked_balance == liabilities and no shortfall
+}
@@ -1,8 +1,15 @@
 use soroban_sdk::{contracttype};
 
+use crate::storage::types::{payment_source::PaymentSource, rental_unit::RentalUnit};
+
 #[derive(Clone)]
 #[contracttype]
 pub struct Rental {
-    pub total_days_to_rent: u32,
+    pub unit: RentalUnit,
+    pub units: u32,
+    pub duration: u64, // Seconds, the time base for pricing, settlement and lateness
     pub amount: i128,
-}
\ No newline at end of file
+    pub start_time: u64,
+    pub settled_amount: i128, // Part of `amount` already moved to the owner's settled balance
+    pub payment: PaymentSource, // Refunds go back to this source
+}
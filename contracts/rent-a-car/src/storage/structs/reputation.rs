@@ -0,0 +1,18 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct Reputation {
+    pub rating_count: u32,
+    pub rating_sum: u32, // Ratings are 1-5, average is rating_sum / rating_count
+    pub late_returns: u32,
+    pub disputes_lost: u32,
+}
+
+// Which side has already rated the last completed rental between a renter and a car
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RatingStatus {
+    pub owner_rated: bool,
+    pub renter_rated: bool,
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RevenueShare {
+    pub beneficiary: Address,
+    pub bps: u32, // Shares of a car always add up to 10,000
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TaxConfig {
+    pub rate_bps: u32,      // Applied to base rent plus commission
+    pub collector: Address, // Tax authority `remit_tax` pays out to
+}
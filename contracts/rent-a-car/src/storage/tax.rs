@@ -0,0 +1,34 @@
+use soroban_sdk::Env;
+
+use crate::storage::{structs::tax_config::TaxConfig, types::storage::DataKey};
+
+pub(crate) fn read_tax_config(env: &Env) -> Option<TaxConfig> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TaxConfig)
+}
+
+pub(crate) fn write_tax_config(env: &Env, config: &TaxConfig) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TaxConfig, config);
+}
+
+// No configuration means no tax is withheld
+pub(crate) fn read_tax_rate(env: &Env) -> u32 {
+    read_tax_config(env).map_or(0, |config| config.rate_bps)
+}
+
+// Tax withheld from rentals and not yet remitted to the collector
+pub(crate) fn read_tax_balance(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TaxBalance)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_tax_balance(env: &Env, amount: &i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TaxBalance, amount);
+}
@@ -1,23 +1,78 @@
 use soroban_sdk::contracterror;
 
+// Codes are part of the public interface: clients match on them, so never
+// renumber or reuse one. Groups leave room to grow:
+// 1-9 setup and access, 10-19 car state, 20-29 rentals, 30-39 amounts and
+// balances, 40-49 arithmetic, 50-59 reputation and renter screening,
+// 60-69 booking rules, 70-79 promotions, 80-89 corporate accounts,
+// 90-99 insurance. Soroban caps a contract error enum at 50 cases, so
+// prefer an existing code when it already describes the failure.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum Error {
-    ContractInitialized = 0,
-    ContractNotInitialized = 1,
-    CarNotFound = 2,
-    AdminTokenConflict = 3,
-    CarAlreadyExist = 5,
-    AmountMustBePositive = 6,
-    RentalDurationCannotBeZero = 10,
-    SelfRentalNotAllowed = 11,
+    ContractInitialized = 1,
+    AdminTokenConflict = 2,
+    Unauthorized = 3, // Also a sponsor paying for a renter it has not approved, or
+                      // a car token sent by an address that doesn't hold it
+
+    CarNotFound = 10,
+    CarAlreadyExist = 11,
     CarAlreadyRented = 12,
-    RentalNotFound = 7,
-    InsufficientBalance = 8,
-    BalanceNotAvailableForAmountRequested = 9,
-    Overflow = 13,
-    Underflow = 14,
-    CarNotReturned = 15,
-    InvalidCommissionAmount = 16, // Commission amount must be positive
-}
\ No newline at end of file
+    CarNotRented = 13,
+    CarNotReturned = 14, // Car still rented where it must be back, e.g. removal
+    CarInMaintenance = 15,
+
+    RentalNotFound = 20,
+    RentalDurationCannotBeZero = 21,
+    SelfRentalNotAllowed = 22,
+    RentalExpired = 23, // Quote asked for a start that has already passed
+    InvalidTimeRange = 24,
+    AmountDoesNotMatchQuote = 25, // Quoted rent is above the most the renter accepts
+    RenterNotVerified = 26,
+    RenterVerificationExpired = 27,
+    RenterNotEligible = 28,
+    EligibilityCheckFailed = 29,
+
+    AmountMustBePositive = 30,
+    InvalidCommissionAmount = 31, // Commission amount must be positive
+    InsufficientBalance = 32,
+    NoSurplusToSweep = 33,
+    InsufficientAllowance = 34, // Payer has not approved enough for the contract to pull
+    InvalidBasisPoints = 35,    // Rates and shares are in basis points, at most 10,000
+
+    Overflow = 40,
+    Underflow = 41,
+
+    InvalidRating = 50,
+    RatingNotAllowed = 51, // No completed rental between the two parties
+    AlreadyRated = 52,
+    RenterScoreTooLow = 53,
+    RenterBlockedByOwner = 54,
+    RenterBlockedGlobally = 55,
+
+    // Booking rules
+    InvalidRentalLimits = 60, // min_days must be at least 1 and not above max_days
+    RentalTooShort = 61,
+    RentalTooLong = 62,
+    HourlyRentalsNotOffered = 63,
+    InvalidPricingRule = 64,
+    TooManyPricingRules = 65,
+
+    // Promotions
+    InvalidPromoCode = 70,
+    PromoCodeAlreadyExists = 71,
+    PromoCodeNotFound = 72,
+    PromoCodeExpired = 73,
+    PromoCodeExhausted = 74,
+    PromoCodeLimitReached = 75, // Renter already used the code as many times as allowed
+
+    // Corporate accounts
+    CorporateAccountNotFound = 80,
+    CorporateAccountAlreadyExists = 81,
+    NotCorporateDriver = 82,
+    CorporateLimitExceeded = 83,
+
+    // Insurance
+    InsuranceNotOffered = 90,
+}
@@ -1,3 +1,6 @@
 pub mod car_status;
 pub mod storage;
-pub mod errors;
\ No newline at end of file
+pub mod errors;pub mod rental_unit;
+pub mod pricing_rule;
+pub mod promo_discount;
+pub mod payment_source;
@@ -0,0 +1,20 @@
+use soroban_sdk::{contracttype, Address};
+
+// Who pays for a rental
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum PaymentSource {
+    Renter,
+    Credit,             // Drawn from the renter's prepaid credit
+    Sponsor(Address),   // Pulled from the sponsor's token allowance
+    Corporate(Address), // Drawn from the prepaid balance of this corporate account
+}
+
+impl PaymentSource {
+    pub fn payer(&self, renter: &Address) -> Address {
+        match self {
+            PaymentSource::Renter | PaymentSource::Credit => renter.clone(),
+            PaymentSource::Sponsor(payer) | PaymentSource::Corporate(payer) => payer.clone(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::{contracttype};
+
+// Adjustments are in basis points of the car's unit price: 2_000 is a 20%
+// surcharge, -1_500 a 15% reduction.
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum PricingRule {
+    Weekend(i32),          // Adjustment for units starting on Saturday or Sunday (UTC)
+    Season(u64, u64, i32), // Adjustment for units starting within [start, end)
+    LongRental(u32, u32),  // Discount in bps for rentals of at least this many days
+}
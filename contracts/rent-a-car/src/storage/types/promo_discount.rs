@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum PromoDiscount {
+    Bps(u32),    // Share of the base rent, in basis points
+    Fixed(i128), // Flat amount in stroops
+}
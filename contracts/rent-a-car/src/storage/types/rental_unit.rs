@@ -0,0 +1,20 @@
+use soroban_sdk::{contracttype};
+
+use crate::methods::pricing::{SECONDS_PER_DAY, SECONDS_PER_HOUR};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum RentalUnit {
+    Hour,
+    Day,
+}
+
+impl RentalUnit {
+    pub fn seconds(&self) -> u64 {
+        match self {
+            RentalUnit::Hour => SECONDS_PER_HOUR,
+            RentalUnit::Day => SECONDS_PER_DAY,
+        }
+    }
+}
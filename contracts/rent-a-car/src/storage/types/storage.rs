@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN};
 
 #[derive(Clone)]
 #[contracttype]
@@ -7,7 +7,39 @@ pub enum DataKey {
     Token,
     ContractBalance,
     Car(Address),
+    OwnerBalances,
+    PendingBalances,
     Rental(Address, Address),
     AdminFee,
     AdminFeesBalance,
+    PayoutAddress(Address),
+    Attestor,
+    RenterVerification(Address),
+    EligibilityVerifier,
+    Reputation(Address),
+    RatingStatus(Address, Address),
+    OwnerBlocklist(Address, Address),
+    GlobalBlocklist(Address),
+    PricingRules(Address),
+    PromoCode(BytesN<32>),
+    PromoRedemptions(BytesN<32>, Address),
+    CorporateAccount(Address),
+    CorporateDriver(Address, Address),
+    CorporateBalances,
+    Credit(Address),
+    CreditBalances,
+    SponsoredRenter(Address, Address),
+    InsurancePlan,
+    InsurancePool,
+    Coverage(u64),
+    CoverageCount,
+    TaxConfig,
+    TaxBalance,
+    RevenueShares(Address),
+    ShareBalance(Address, Address),
+    ShareBalances,
+    CarTokenHolder(u32),
+    CarTokenListing(u32),
+    CarTokenBalance(Address),
+    CarTokenCount,
 }
\ No newline at end of file
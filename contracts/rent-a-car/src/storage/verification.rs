@@ -0,0 +1,48 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+// Optional address (account or contract) allowed to verify renters besides the admin
+pub(crate) fn read_attestor(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Attestor)
+}
+
+pub(crate) fn write_attestor(env: &Env, attestor: &Address) {
+    env.storage().instance().set(&DataKey::Attestor, attestor);
+}
+
+pub(crate) fn remove_attestor(env: &Env) {
+    env.storage().instance().remove(&DataKey::Attestor);
+}
+
+// Ledger timestamp until which the renter is verified
+pub(crate) fn read_renter_verification(env: &Env, renter: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RenterVerification(renter.clone()))
+}
+
+pub(crate) fn write_renter_verification(env: &Env, renter: &Address, expires_at: &u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RenterVerification(renter.clone()), expires_at);
+}
+
+pub(crate) fn remove_renter_verification(env: &Env, renter: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RenterVerification(renter.clone()));
+}
+
+// Optional external contract consulted by `rental` for renter eligibility
+pub(crate) fn read_eligibility_verifier(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::EligibilityVerifier)
+}
+
+pub(crate) fn write_eligibility_verifier(env: &Env, verifier: &Address) {
+    env.storage().instance().set(&DataKey::EligibilityVerifier, verifier);
+}
+
+pub(crate) fn remove_eligibility_verifier(env: &Env) {
+    env.storage().instance().remove(&DataKey::EligibilityVerifier);
+}
@@ -1,5 +1,9 @@
-use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, vec, IntoVal, Symbol};
-use crate::{storage::{car::read_car, types::car_status::CarStatus}, tests::config::{contract::ContractTest, utils::get_contract_events}};
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, IntoVal};
+use crate::{
+    events::add_car::CarAddedEvent,
+    storage::{car::read_car, types::car_status::CarStatus},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
 
 #[test]
 pub fn test_add_car_successfully() {
@@ -10,7 +14,7 @@ pub fn test_add_car_successfully() {
 
     env.mock_all_auths();
     let commission_amount: i128 = 1_000_000_000_i128; // 1 XLM in stroops
-    contract.add_car(&owner, &price_per_day, &commission_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
     let contract_events = get_contract_events(&env, &contract.address);
 
     let stored_car = env.as_contract(&contract.address, || {
@@ -19,25 +23,26 @@ pub fn test_add_car_successfully() {
 
     assert_eq!(stored_car.price_per_day, price_per_day);
     assert_eq!(stored_car.car_status, CarStatus::Available);
+    assert_eq!(contract.get_rental_limits(&owner), (1, 30));
     
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "car_added", &owner));
+
+    let event: CarAddedEvent = data.into_val(&env);
     assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "car_added").as_val(),
-                    owner.clone().into_val(&env),
-                ],
-                price_per_day.into_val(&env)
-            )
-        ]
+        event,
+        CarAddedEvent {
+            owner: owner.clone(),
+            token_id: 1,
+            price_per_day,
+            commission_amount,
+        }
     );
 }
 #[test]
-#[should_panic(expected = "Error(Contract, #6)")]
+#[should_panic(expected = "Error(Contract, #30)")]
 pub fn test_add_car_with_zero_price_fails() {
     let ContractTest { contract, env, .. } = ContractTest::setup();
     let owner = Address::generate(&env);
@@ -46,10 +51,10 @@ pub fn test_add_car_with_zero_price_fails() {
     env.mock_all_auths();
     let commission_amount: i128 = 1_000_000_000_i128; // 1 XLM in stroops
 
-    contract.add_car(&owner, &price_per_day, &commission_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
 }
 #[test]
-#[should_panic(expected = "Error(Contract, #6)")]
+#[should_panic(expected = "Error(Contract, #30)")]
 pub fn test_add_car_with_negative_price_fails() {
     let ContractTest { contract, env, .. } = ContractTest::setup();
     let owner = Address::generate(&env);
@@ -58,11 +63,11 @@ pub fn test_add_car_with_negative_price_fails() {
     env.mock_all_auths();
     let commission_amount: i128 = 1_000_000_000_i128; // 1 XLM in stroops
 
-    contract.add_car(&owner, &price_per_day, &commission_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #5)")]
+#[should_panic(expected = "Error(Contract, #11)")]
 pub fn test_add_car_already_exists_fails() {
     let ContractTest { contract, env, .. } = ContractTest::setup();
     let owner = Address::generate(&env);
@@ -71,8 +76,18 @@ pub fn test_add_car_already_exists_fails() {
     env.mock_all_auths();
     let commission_amount: i128 = 1_000_000_000_i128;
 
-    contract.add_car(&owner, &price_per_day, &commission_amount);
-    contract.add_car(&owner, &price_per_day, &commission_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #60)")]
+pub fn test_add_car_with_min_days_above_max_days_fails() {
+    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.add_car(&owner, &1500_i128, &1_000_000_000_i128, &7, &3);
 }
 
 #[test]
@@ -89,11 +104,11 @@ pub fn test_unauthorized_user_cannot_add_car() {
         invoke: &MockAuthInvoke {
             contract: &contract.address,
             fn_name: "add_car",
-            args: (owner.clone(), price_per_day, 1_000_000_000_i128 as i128).into_val(&env),
+            args: (owner.clone(), price_per_day, 1_000_000_000_i128, 1_u32, 30_u32).into_val(&env),
             sub_invokes: &[],
         },
     }]);
 
     let commission_amount: i128 = 1_000_000_000_i128;
-    contract.add_car(&owner, &price_per_day, &commission_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
 }
\ No newline at end of file
@@ -0,0 +1,79 @@
+use soroban_sdk::{testutils::Address as _, Address, IntoVal};
+use crate::{
+    events::{contract::AdminFeeSetEvent, payout_owner::AdminFeesWithdrawnEvent},
+    storage::types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_set_admin_fee_successfully() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.set_admin_fee(&250);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_admin_fee(), 250);
+
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "admin_fee_set", &admin));
+
+    let event: AdminFeeSetEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        AdminFeeSetEvent {
+            admin: admin.clone(),
+            previous_fee: 0,
+            fee: 250,
+        }
+    );
+}
+
+#[test]
+pub fn test_withdraw_admin_fees_successfully() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    let amount = 400_000_000_i128;
+    contract.withdraw_admin_fees(&amount);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&admin), amount);
+    assert_eq!(contract.get_admin_fees_balance(), commission_amount - amount);
+
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "admin_fees_withdrawn", &admin));
+
+    let event: AdminFeesWithdrawnEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        AdminFeesWithdrawnEvent {
+            admin: admin.clone(),
+            amount,
+            balance_before: commission_amount,
+            balance_after: commission_amount - amount,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_withdraw_admin_fees_insufficient_balance_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.withdraw_admin_fees(&1);
+}
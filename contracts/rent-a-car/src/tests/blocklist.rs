@@ -0,0 +1,106 @@
+use soroban_sdk::{testutils::Address as _, Address, IntoVal};
+use crate::{
+    events::blocklist::RenterBlocklistEvent,
+    storage::types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_owner_blocks_and_unblocks_renter() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    contract.block_renter(&owner, &renter);
+    let contract_events = get_contract_events(&env, &contract.address);
+    assert!(contract.is_renter_blocked(&owner, &renter));
+    assert!(!contract.is_renter_blocked(&Address::generate(&env), &renter));
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "renter_blocked", &owner));
+    let event: RenterBlocklistEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        RenterBlocklistEvent { blocked_by: owner.clone(), renter: renter.clone(), global: false, blocked: true }
+    );
+
+    contract.unblock_renter(&owner, &renter);
+    assert!(!contract.is_renter_blocked(&owner, &renter));
+
+    token.1.mint(&renter, &(3000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    assert!(contract.has_rental(&renter, &owner));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #54)")]
+pub fn test_rental_by_owner_blocked_renter_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.block_renter(&owner, &renter);
+
+    token.1.mint(&renter, &(3000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+pub fn test_global_block_applies_to_every_car() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.block_renter_globally(&renter);
+    let contract_events = get_contract_events(&env, &contract.address);
+    assert!(contract.is_renter_blocked(&owner, &renter));
+    assert!(contract.is_renter_blocked(&other_owner, &renter));
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "renter_blocked", &admin));
+    let event: RenterBlocklistEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        RenterBlocklistEvent { blocked_by: admin.clone(), renter: renter.clone(), global: true, blocked: true }
+    );
+
+    contract.unblock_renter_globally(&renter);
+    assert!(!contract.is_renter_blocked(&owner, &renter));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #55)")]
+pub fn test_rental_by_globally_blocked_renter_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.block_renter_globally(&renter);
+
+    token.1.mint(&renter, &(3000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+pub fn test_block_renter_without_car_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.block_renter(&Address::generate(&env), &Address::generate(&env));
+}
@@ -0,0 +1,170 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, Address, IntoVal};
+use crate::{
+    events::car_token::CarTokenTransferredEvent,
+    storage::{structs::revenue_share::RevenueShare, types::{payment_source::PaymentSource, rental_unit::RentalUnit}},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_add_car_mints_token_to_owner() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    contract.add_car(&owner1, &1000_i128, &100_i128, &1, &30);
+    contract.add_car(&owner2, &1000_i128, &100_i128, &1, &30);
+
+    assert_eq!(contract.get_car_token(&owner1), 1);
+    assert_eq!(contract.get_car_token(&owner2), 2);
+    assert_eq!(contract.owner_of(&1), owner1);
+    assert_eq!(contract.balance(&owner1), 1);
+}
+
+#[test]
+pub fn test_token_holder_controls_the_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &(3100 * 2));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+    contract.set_revenue_shares(&owner, &vec![&env, RevenueShare { beneficiary: investor, bps: 10_000 }]);
+
+    contract.transfer(&owner, &holder, &1);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    // Earnings and co-owner shares up to the transfer stay with the previous holder
+    assert_eq!(token_client.balance(&owner), 3000);
+    assert_eq!(contract.get_revenue_shares(&owner).len(), 0);
+    assert_eq!(contract.owner_of(&1), holder);
+    assert_eq!((contract.balance(&owner), contract.balance(&holder)), (0, 1));
+    assert_eq!(contract.get_payout_address(&owner), holder);
+
+    let (_, topics, data) = contract_events.last().unwrap();
+    assert_eq!(topics, event_topics(&env, "car_token_transferred", &owner));
+    let event: CarTokenTransferredEvent = data.into_val(&env);
+    assert_eq!(event, CarTokenTransferredEvent {
+        owner: owner.clone(),
+        token_id: 1,
+        from: owner.clone(),
+        to: holder.clone(),
+        paid_out: 3000,
+    });
+
+    // The car stays listed under the same key and pays the new holder
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+    contract.payout_owner_all(&owner);
+
+    assert_eq!(token_client.balance(&holder), 3000);
+    assert!(contract.reconcile().is_consistent);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_previous_holder_cannot_update_car() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let holder = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.transfer(&owner, &holder, &1);
+
+    env.mock_auths(&[MockAuth {
+        address: &owner,
+        invoke: &MockAuthInvoke {
+            contract: &contract.address,
+            fn_name: "set_hourly_price",
+            args: (owner.clone(), 80_i128).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    contract.set_hourly_price(&owner, &80);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+pub fn test_transfer_by_non_holder_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    contract.transfer(&stranger, &stranger, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_transfer_token_of_rented_car_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    token.1.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    contract.transfer(&owner, &holder, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+pub fn test_holder_cannot_rent_own_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let holder = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.transfer(&owner, &holder, &1);
+
+    token.1.mint(&holder, &3100);
+    contract.rental(&holder, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+pub fn test_remove_car_burns_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.remove_car(&owner);
+
+    assert_eq!(contract.balance(&owner), 0);
+    assert!(contract.try_owner_of(&1).is_err());
+
+    // Ids are not reused
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    assert_eq!(contract.get_car_token(&owner), 2);
+}
+
+#[test]
+pub fn test_transfer_car_moves_token_to_buyer() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.transfer_car(&owner, &buyer);
+
+    assert_eq!(contract.get_car_token(&buyer), 1);
+    assert_eq!(contract.owner_of(&1), buyer);
+    assert_eq!((contract.balance(&owner), contract.balance(&buyer)), (0, 1));
+}
@@ -1,2 +1,3 @@
 pub mod contract;
-pub mod utils;
\ No newline at end of file
+pub mod utils;
+pub mod verifier;
@@ -1,4 +1,6 @@
-use soroban_sdk::{testutils::Events, token, Address, Env, Val, Vec};
+use soroban_sdk::{testutils::Events, token, vec, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::events::EVENTS_VERSION;
 
 pub(crate) fn create_token_contract<'a>(
     e: &Env,
@@ -25,4 +27,15 @@ pub(crate) fn get_contract_events(
         .for_each(|event| contract_events.push_back(event));
 
     contract_events
-}
\ No newline at end of file
+}
+
+// Expected topics for a contract event: (contract name, version, action, subject)
+pub(crate) fn event_topics(env: &Env, action: &str, subject: &Address) -> Vec<Val> {
+    vec![
+        env,
+        Symbol::new(env, "rent_a_car").into_val(env),
+        EVENTS_VERSION.into_val(env),
+        Symbol::new(env, action).into_val(env),
+        subject.into_val(env),
+    ]
+}
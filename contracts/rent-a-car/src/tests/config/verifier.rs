@@ -0,0 +1,32 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
+use crate::interfaces::eligibility::EligibilityVerifier;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum MockVerifierMode {
+    Accept,
+    Reject,
+    Panic,
+}
+
+#[contract]
+pub struct MockVerifier;
+
+#[contractimpl]
+impl MockVerifier {
+    pub fn __constructor(env: Env, mode: MockVerifierMode) {
+        env.storage().instance().set(&Symbol::new(&env, "mode"), &mode);
+    }
+}
+
+#[contractimpl]
+impl EligibilityVerifier for MockVerifier {
+    fn is_eligible(env: Env, _renter: Address, _car: Address) -> bool {
+        let mode: MockVerifierMode = env.storage().instance().get(&Symbol::new(&env, "mode")).unwrap();
+        match mode {
+            MockVerifierMode::Accept => true,
+            MockVerifierMode::Reject => false,
+            MockVerifierMode::Panic => panic!("verifier failure"),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, IntoVal};
+use crate::{
+    events::corporate::CorporateChargedEvent,
+    storage::types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+const HOUR: u64 = 3_600;
+const DAY: u64 = 86_400;
+const JAN_31_2024: u64 = 1_706_659_200;
+
+#[test]
+pub fn test_driver_rents_from_corporate_balance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let company = Address::generate(&env);
+    let driver = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&company, &6000);
+    contract.open_corporate_account(&company, &5000);
+    contract.set_corporate_driver(&company, &driver, &true);
+    contract.fund_corporate_account(&company, &6000);
+    assert_eq!(token_client.balance(&company), 0);
+    assert_eq!(contract.get_corporate_account(&company).balance, 6000);
+
+    contract.rental(&driver, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Corporate(company.clone()), &false);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&driver), 0);
+    assert_eq!(contract.get_corporate_account(&company).balance, 2900);
+    assert_eq!(contract.get_corporate_spend(&company), (3100, 1900));
+    assert_eq!(contract.get_car_balances(&owner), (0, 3000));
+
+    let report = contract.reconcile();
+    assert_eq!(report.corporate_balances, 2900);
+    assert_eq!(report.token_balance, 6000);
+    assert!(report.is_consistent);
+
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "corporate_charged", &company));
+    let event: CorporateChargedEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        CorporateChargedEvent {
+            company: company.clone(),
+            driver: driver.clone(),
+            amount: 3100,
+            period_spent: 3100,
+            balance_after: 2900,
+        }
+    );
+}
+
+#[test]
+pub fn test_corporate_spend_resets_each_calendar_month() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(JAN_31_2024 + 23 * HOUR);
+
+    let owner = Address::generate(&env);
+    let company = Address::generate(&env);
+    let driver = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    token.1.mint(&company, &10_000);
+    contract.open_corporate_account(&company, &5000);
+    contract.set_corporate_driver(&company, &driver, &true);
+    contract.fund_corporate_account(&company, &10_000);
+
+    contract.rental(&driver, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Corporate(company.clone()), &false);
+    contract.return_car(&driver, &owner);
+    assert_eq!(contract.get_corporate_spend(&company), (3100, 1900));
+
+    // One hour later it is February
+    env.ledger().with_mut(|ledger| ledger.timestamp += HOUR);
+    assert_eq!(contract.get_corporate_spend(&company), (0, 5000));
+
+    contract.rental(&driver, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Corporate(company.clone()), &false);
+    assert_eq!(contract.get_corporate_spend(&company), (3100, 1900));
+
+    // Leap day is still February
+    env.ledger().set_timestamp(JAN_31_2024 + 29 * DAY);
+    assert_eq!(contract.get_corporate_spend(&company), (3100, 1900));
+    env.ledger().set_timestamp(JAN_31_2024 + 30 * DAY);
+    assert_eq!(contract.get_corporate_spend(&company), (0, 5000));
+}
+
+#[test]
+pub fn test_company_withdraws_unused_balance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let company = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&company, &6000);
+    contract.open_corporate_account(&company, &5000);
+    contract.fund_corporate_account(&company, &6000);
+
+    contract.withdraw_corporate_balance(&company, &4000);
+    assert_eq!(token_client.balance(&company), 4000);
+    assert_eq!(contract.get_corporate_account(&company).balance, 2000);
+    assert!(contract.reconcile().is_consistent);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #82)")]
+pub fn test_unauthorized_driver_cannot_use_corporate_balance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let company = Address::generate(&env);
+    let driver = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    token.1.mint(&company, &6000);
+    contract.open_corporate_account(&company, &5000);
+    contract.fund_corporate_account(&company, &6000);
+
+    contract.rental(&driver, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Corporate(company.clone()), &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #83)")]
+pub fn test_rental_above_monthly_limit_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    let company = Address::generate(&env);
+    let driver = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.add_car(&other_owner, &1000_i128, &100_i128, &1, &30);
+
+    token.1.mint(&company, &10_000);
+    contract.open_corporate_account(&company, &5000);
+    contract.set_corporate_driver(&company, &driver, &true);
+    contract.fund_corporate_account(&company, &10_000);
+
+    contract.rental(&driver, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Corporate(company.clone()), &false);
+    contract.rental(&driver, &other_owner, &RentalUnit::Day, &2, &2000, &None, &PaymentSource::Corporate(company.clone()), &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_rental_above_corporate_balance_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let company = Address::generate(&env);
+    let driver = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    token.1.mint(&company, &1000);
+    contract.open_corporate_account(&company, &5000);
+    contract.set_corporate_driver(&company, &driver, &true);
+    contract.fund_corporate_account(&company, &1000);
+
+    contract.rental(&driver, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Corporate(company.clone()), &false);
+}
@@ -0,0 +1,98 @@
+use soroban_sdk::{testutils::Address as _, Address, IntoVal};
+use crate::{
+    events::credit::CreditEvent,
+    storage::{rental::read_rental, types::{payment_source::PaymentSource, rental_unit::RentalUnit}},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_renter_deposits_and_withdraws_credit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.deposit_credit(&renter, &10_000);
+    let contract_events = get_contract_events(&env, &contract.address);
+    assert_eq!(contract.get_credit(&renter), 10_000);
+    assert_eq!(token_client.balance(&contract.address), 10_000);
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "credit_deposited", &renter));
+    let event: CreditEvent = data.into_val(&env);
+    assert_eq!(event, CreditEvent { renter: renter.clone(), amount: 10_000, balance_after: 10_000 });
+
+    contract.withdraw_credit(&renter, &4_000);
+    assert_eq!(contract.get_credit(&renter), 6_000);
+    assert_eq!(token_client.balance(&renter), 4_000);
+
+    let report = contract.reconcile();
+    assert_eq!(report.credit_balances, 6_000);
+    assert!(report.is_consistent);
+}
+
+#[test]
+pub fn test_rental_paid_from_credit_needs_no_transfer() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &5_000);
+    contract.deposit_credit(&renter, &5_000);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Credit, &false);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_credit(&renter), 1_900);
+    assert_eq!(token_client.balance(&contract.address), 5_000);
+    assert_eq!(contract.get_car_balances(&owner), (0, 3000));
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.payment, PaymentSource::Credit);
+
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "credit_charged", &renter));
+    let event: CreditEvent = data.into_val(&env);
+    assert_eq!(event, CreditEvent { renter: renter.clone(), amount: 3100, balance_after: 1_900 });
+
+    let report = contract.reconcile();
+    assert_eq!(report.credit_balances, 1_900);
+    assert_eq!(report.liabilities, report.token_balance);
+    assert!(report.is_consistent);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_rental_above_credit_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    token.1.mint(&renter, &5_000);
+    contract.deposit_credit(&renter, &3_000);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Credit, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_withdraw_more_than_credit_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let renter = Address::generate(&env);
+    token.1.mint(&renter, &1_000);
+    contract.deposit_credit(&renter, &1_000);
+
+    contract.withdraw_credit(&renter, &1_001);
+}
@@ -0,0 +1,107 @@
+use soroban_sdk::{testutils::Address as _, Address, IntoVal};
+use crate::{
+    events::verification::EligibilityVerifierSetEvent,
+    storage::types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{
+        contract::ContractTest,
+        utils::{event_topics, get_contract_events},
+        verifier::{MockVerifier, MockVerifierMode},
+    },
+};
+
+#[test]
+pub fn test_rental_with_accepting_verifier_succeeds() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let verifier = env.register(MockVerifier, (MockVerifierMode::Accept,));
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    contract.set_eligibility_verifier(&Some(verifier.clone()));
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_eligibility_verifier(), Some(verifier.clone()));
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "eligibility_verifier_set", &admin));
+    let event: EligibilityVerifierSetEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        EligibilityVerifierSetEvent {
+            admin: admin.clone(),
+            previous: None,
+            verifier: Some(verifier),
+        }
+    );
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    assert!(contract.has_rental(&renter, &owner));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #28)")]
+pub fn test_rental_with_rejecting_verifier_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let verifier = env.register(MockVerifier, (MockVerifierMode::Reject,));
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_eligibility_verifier(&Some(verifier));
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+pub fn test_rental_with_panicking_verifier_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let verifier = env.register(MockVerifier, (MockVerifierMode::Panic,));
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_eligibility_verifier(&Some(verifier));
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+pub fn test_removing_verifier_allows_rentals_again() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let verifier = env.register(MockVerifier, (MockVerifierMode::Reject,));
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_eligibility_verifier(&Some(verifier));
+
+    assert!(contract.try_rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false).is_err());
+    assert_eq!(token_client.balance(&renter), 3000 + commission_amount);
+
+    contract.set_eligibility_verifier(&None);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    assert!(contract.has_rental(&renter, &owner));
+}
@@ -0,0 +1,199 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::{
+    storage::{
+        car::{read_car, write_car},
+        types::{car_status::CarStatus, errors::Error, payment_source::PaymentSource, rental_unit::RentalUnit},
+    },
+    tests::config::contract::ContractTest,
+};
+
+// Error codes are part of the contract interface; changing one is a breaking change
+#[test]
+pub fn test_error_codes_are_stable() {
+    let codes = [
+        (Error::ContractInitialized, 1),
+        (Error::AdminTokenConflict, 2),
+        (Error::Unauthorized, 3),
+        (Error::CarNotFound, 10),
+        (Error::CarAlreadyExist, 11),
+        (Error::CarAlreadyRented, 12),
+        (Error::CarNotRented, 13),
+        (Error::CarNotReturned, 14),
+        (Error::CarInMaintenance, 15),
+        (Error::RentalNotFound, 20),
+        (Error::RentalDurationCannotBeZero, 21),
+        (Error::SelfRentalNotAllowed, 22),
+        (Error::RentalExpired, 23),
+        (Error::InvalidTimeRange, 24),
+        (Error::AmountDoesNotMatchQuote, 25),
+        (Error::RenterNotVerified, 26),
+        (Error::RenterVerificationExpired, 27),
+        (Error::RenterNotEligible, 28),
+        (Error::EligibilityCheckFailed, 29),
+        (Error::AmountMustBePositive, 30),
+        (Error::InvalidCommissionAmount, 31),
+        (Error::InsufficientBalance, 32),
+        (Error::NoSurplusToSweep, 33),
+        (Error::InsufficientAllowance, 34),
+        (Error::InvalidBasisPoints, 35),
+        (Error::Overflow, 40),
+        (Error::Underflow, 41),
+        (Error::InvalidRating, 50),
+        (Error::RatingNotAllowed, 51),
+        (Error::AlreadyRated, 52),
+        (Error::RenterScoreTooLow, 53),
+        (Error::RenterBlockedByOwner, 54),
+        (Error::RenterBlockedGlobally, 55),
+        (Error::InvalidRentalLimits, 60),
+        (Error::RentalTooShort, 61),
+        (Error::RentalTooLong, 62),
+        (Error::HourlyRentalsNotOffered, 63),
+        (Error::InvalidPricingRule, 64),
+        (Error::TooManyPricingRules, 65),
+        (Error::InvalidPromoCode, 70),
+        (Error::PromoCodeAlreadyExists, 71),
+        (Error::PromoCodeNotFound, 72),
+        (Error::PromoCodeExpired, 73),
+        (Error::PromoCodeExhausted, 74),
+        (Error::PromoCodeLimitReached, 75),
+        (Error::CorporateAccountNotFound, 80),
+        (Error::CorporateAccountAlreadyExists, 81),
+        (Error::NotCorporateDriver, 82),
+        (Error::CorporateLimitExceeded, 83),
+        (Error::InsuranceNotOffered, 90),
+    ];
+
+    for (error, code) in codes {
+        assert_eq!(error as u32, code);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+pub fn test_rental_car_in_maintenance_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    env.as_contract(&contract.address, || {
+        let mut car = read_car(&env, &owner);
+        car.car_status = CarStatus::Maintenance;
+        write_car(&env, &owner, &car);
+    });
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_return_car_not_rented_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    env.as_contract(&contract.address, || {
+        let mut car = read_car(&env, &owner);
+        car.car_status = CarStatus::Maintenance;
+        write_car(&env, &owner, &car);
+    });
+
+    contract.return_car(&renter, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_quote_rental_starting_in_the_past_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    contract.quote_rental(&owner, &RentalUnit::Day, &3, &(1_700_000_000 - 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+pub fn test_quote_rental_past_end_of_time_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    contract.quote_rental(&owner, &RentalUnit::Day, &3, &(u64::MAX - 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+pub fn test_self_rental_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    contract.rental(&owner, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_rental_with_zero_days_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &0, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+pub fn test_add_car_with_zero_commission_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &0, &1, &30);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #90)")]
+pub fn test_insured_rental_without_plan_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_tax_rate_above_full_price_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let collector = Address::generate(&env);
+    contract.set_tax_config(&10_001, &collector);
+}
@@ -11,7 +11,7 @@ pub fn test_get_car_status_returns_available() {
     env.mock_all_auths();
     let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
 
-    contract.add_car(&owner, &price_per_day, &commission_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
 
     let is_car_stored = env.as_contract(&contract.address, || {
         has_car(&env, &owner)
@@ -23,7 +23,7 @@ pub fn test_get_car_status_returns_available() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #2)")]
+#[should_panic(expected = "Error(Contract, #10)")]
 pub fn test_get_car_status_car_not_found_fails() {
     let ContractTest { env, contract, .. } = ContractTest::setup();
     let owner = Address::generate(&env);
@@ -0,0 +1,111 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, IntoVal};
+use crate::{
+    events::pricing::HourlyPriceSetEvent,
+    storage::{rental::read_rental, types::{payment_source::PaymentSource, rental_unit::RentalUnit}},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+const HOUR: u64 = 3_600;
+
+#[test]
+pub fn test_hourly_rental_is_priced_and_stored_in_seconds() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    contract.set_hourly_price(&owner, &60_i128);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "hourly_price_set", &owner));
+    let event: HourlyPriceSetEvent = data.into_val(&env);
+    assert_eq!(event, HourlyPriceSetEvent { owner: owner.clone(), previous: 0, price_per_hour: 60 });
+
+    let quote = contract.quote_rental(&owner, &RentalUnit::Hour, &5, &1_700_000_000);
+    assert_eq!(quote.base_rent, 300);
+    assert_eq!(quote.end, 1_700_000_000 + 5 * HOUR);
+
+    token.1.mint(&renter, &quote.total);
+    contract.rental(&renter, &owner, &RentalUnit::Hour, &5, &300, &None, &PaymentSource::Renter, &false);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.unit, RentalUnit::Hour);
+    assert_eq!(rental.units, 5);
+    assert_eq!(rental.duration, 5 * HOUR);
+}
+
+#[test]
+pub fn test_hourly_rental_settles_by_the_hour() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_hourly_price(&owner, &100_i128);
+
+    token.1.mint(&renter, &(400 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Hour, &4, &400, &None, &PaymentSource::Renter, &false);
+
+    env.ledger().set_timestamp(1_700_000_000 + 2 * HOUR + 59);
+    assert_eq!(contract.settle(&owner), 200);
+    assert_eq!(contract.get_car_balances(&owner), (200, 200));
+}
+
+#[test]
+pub fn test_late_hourly_return_is_recorded() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_hourly_price(&owner, &100_i128);
+
+    token.1.mint(&renter, &(200 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Hour, &2, &200, &None, &PaymentSource::Renter, &false);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp += 2 * HOUR + 1);
+    contract.return_car(&renter, &owner);
+    assert_eq!(contract.get_reputation(&renter).late_returns, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #63)")]
+pub fn test_hourly_rental_without_hourly_price_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    token.1.mint(&renter, &(1000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Hour, &2, &1000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #62)")]
+pub fn test_hourly_rental_longer_than_max_days_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &1);
+    contract.set_hourly_price(&owner, &100_i128);
+
+    token.1.mint(&renter, &(2500 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Hour, &25, &2500, &None, &PaymentSource::Renter, &false);
+}
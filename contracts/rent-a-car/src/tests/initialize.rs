@@ -1,5 +1,8 @@
-use soroban_sdk::{vec, Symbol, IntoVal};
-use crate::tests::config::{contract::ContractTest, utils::get_contract_events};
+use soroban_sdk::IntoVal;
+use crate::{
+    events::contract::ContractInitializedEvent,
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
 
 #[test]
 pub fn test_initialize() {
@@ -9,18 +12,17 @@ pub fn test_initialize() {
     let contract_admin = contract.get_admin();
 
     assert_eq!(admin, contract_admin);
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "contract_initialized", &admin));
+
+    let event: ContractInitializedEvent = data.into_val(&env);
     assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "contract_initialized").as_val(),
-                ],
-                (admin.clone(), token.0.address.clone()).into_val(&env)
-            )
-        ]
+        event,
+        ContractInitializedEvent {
+            admin: admin.clone(),
+            token: token.0.address.clone(),
+        }
     );
 }
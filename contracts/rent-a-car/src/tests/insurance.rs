@@ -0,0 +1,250 @@
+use soroban_sdk::{testutils::Address as _, Address, IntoVal};
+use crate::{
+    events::insurance::{CoveragePurchasedEvent, InsuranceClaimPaidEvent},
+    storage::{
+        structs::insurance::{Coverage, InsurancePlan, InsurancePool},
+        types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    },
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_insured_rental_pays_premium_into_pool() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_insurance_plan(&50, &2000);
+    assert_eq!(contract.get_insurance_plan(), Some(InsurancePlan { premium_per_day: 50, coverage_cap: 2000 }));
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &3250);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&renter), 0);
+    assert_eq!(contract.get_admin_fees_balance(), 100);
+    assert_eq!(contract.get_insurance_pool(), InsurancePool { balance: 150, premiums_collected: 150, claims_paid: 0 });
+    assert_eq!(
+        contract.get_coverage(&1),
+        Some(Coverage {
+            owner: owner.clone(),
+            token_id: 1,
+            holder: owner.clone(),
+            renter: renter.clone(),
+            premium: 150,
+            coverage_cap: 2000,
+            claimed: 0,
+        })
+    );
+
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "coverage_purchased", &renter));
+    let event: CoveragePurchasedEvent = data.into_val(&env);
+    assert_eq!(event, CoveragePurchasedEvent {
+        coverage_id: 1,
+        owner: owner.clone(),
+        renter: renter.clone(),
+        premium: 150,
+        coverage_cap: 2000,
+        pool_after: 150,
+    });
+
+    let report = contract.reconcile();
+    assert_eq!(report.insurance_pool, 150);
+    assert!(report.is_consistent);
+}
+
+#[test]
+pub fn test_hourly_rental_pays_one_day_of_premium() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_hourly_price(&owner, &60);
+    contract.set_insurance_plan(&50, &2000);
+
+    token.1.mint(&renter, &330);
+    contract.rental(&renter, &owner, &RentalUnit::Hour, &3, &180, &None, &PaymentSource::Renter, &true);
+
+    assert_eq!(token.0.balance(&renter), 0);
+    assert_eq!(contract.get_coverage(&1).unwrap().premium, 50);
+}
+
+#[test]
+pub fn test_uninsured_rental_has_no_coverage() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_insurance_plan(&50, &2000);
+
+    token.1.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    assert_eq!(contract.get_coverage(&1), None);
+    assert_eq!(contract.get_insurance_pool().balance, 0);
+}
+
+#[test]
+pub fn test_claim_is_paid_from_pool_to_owner() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_insurance_plan(&500, &2000);
+
+    token.1.mint(&renter, &4600);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+    contract.return_car(&renter, &owner);
+
+    contract.pay_insurance_claim(&1, &1200);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car_balances(&owner), (4200, 0));
+    assert_eq!(contract.get_insurance_pool(), InsurancePool { balance: 300, premiums_collected: 1500, claims_paid: 1200 });
+    assert_eq!(contract.get_coverage(&1).unwrap().claimed, 1200);
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "insurance_claim_paid", &owner));
+    let event: InsuranceClaimPaidEvent = data.into_val(&env);
+    assert_eq!(event, InsuranceClaimPaidEvent {
+        admin: contract.get_admin(),
+        coverage_id: 1,
+        owner: owner.clone(),
+        renter: renter.clone(),
+        amount: 1200,
+        claimed: 1200,
+        pool_after: 300,
+        available_after: 4200,
+    });
+
+    let report = contract.reconcile();
+    assert_eq!(report.insurance_pool, 300);
+    assert!(report.is_consistent);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_claim_above_coverage_cap_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_insurance_plan(&1000, &2000);
+
+    token.1.mint(&renter, &6100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+
+    contract.pay_insurance_claim(&1, &1500);
+    contract.pay_insurance_claim(&1, &501);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_claim_above_pool_balance_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_insurance_plan(&50, &2000);
+
+    token.1.mint(&renter, &3250);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+
+    contract.pay_insurance_claim(&1, &151);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_claim_on_uninsured_rental_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    token.1.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    contract.pay_insurance_claim(&1, &1);
+}
+
+#[test]
+pub fn test_zero_premium_stops_offering_coverage() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.set_insurance_plan(&50, &2000);
+    contract.set_insurance_plan(&0, &0);
+
+    assert_eq!(contract.get_insurance_plan(), None);
+}
+
+#[test]
+pub fn test_next_rental_keeps_earlier_coverage_claimable() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_insurance_plan(&500, &2000);
+
+    token.1.mint(&renter, &(4600 + 3100 + 4600));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+    contract.return_car(&renter, &owner);
+    contract.pay_insurance_claim(&1, &500);
+
+    // Neither an uninsured nor an insured rental touches the first coverage
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+
+    assert_eq!(contract.get_coverage(&1).unwrap().claimed, 500);
+    assert_eq!(contract.get_coverage(&2).unwrap().claimed, 0);
+
+    contract.pay_insurance_claim(&1, &1500);
+    assert_eq!(contract.get_coverage(&1).unwrap().claimed, 2000);
+    assert!(contract.reconcile().is_consistent);
+}
+
+#[test]
+pub fn test_claim_counts_one_lost_dispute_per_coverage() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_insurance_plan(&500, &2000);
+
+    token.1.mint(&renter, &(4600 * 2));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+    contract.return_car(&renter, &owner);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+    contract.return_car(&renter, &owner);
+    assert_eq!(contract.get_reputation(&renter).disputes_lost, 0);
+
+    contract.pay_insurance_claim(&1, &500);
+    contract.pay_insurance_claim(&1, &500);
+    assert_eq!(contract.get_reputation(&renter).disputes_lost, 1);
+
+    contract.pay_insurance_claim(&2, &500);
+    assert_eq!(contract.get_reputation(&renter).disputes_lost, 2);
+}
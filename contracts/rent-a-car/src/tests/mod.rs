@@ -6,4 +6,28 @@ pub mod add_car;
 pub mod get_car_status;
 pub mod rental;
 pub mod remove_car;
-pub mod payout_owner;
\ No newline at end of file
+pub mod payout_owner;
+pub mod quote_rental;
+pub mod settle;
+pub mod payout_address;
+pub mod reconcile;
+pub mod sweep_surplus;
+pub mod return_car;
+pub mod admin_fees;
+pub mod errors;
+pub mod verification;
+pub mod eligibility;
+pub mod reputation;
+pub mod blocklist;
+pub mod rental_limits;
+pub mod hourly_rental;
+pub mod pricing_rules;
+pub mod promo_codes;
+pub mod payer;
+pub mod corporate;
+pub mod credit;
+pub mod insurance;
+pub mod tax;
+pub mod revenue_split;
+pub mod transfer_car;
+pub mod car_token;
@@ -0,0 +1,130 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, IntoVal};
+use crate::{
+    events::rental::RentedEvent,
+    storage::{rental::read_rental, types::{payment_source::PaymentSource, rental_unit::RentalUnit}},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_sponsor_pays_rental_through_allowance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let total_amount = 3000 + commission_amount;
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &total_amount);
+    token_client.approve(&employer, &contract.address, &total_amount, &(env.ledger().sequence() + 100));
+
+    contract.set_sponsored_renter(&employer, &renter, &true);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Sponsor(employer.clone()), &false);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    // The employer approved the renter up front, so only the renter signs the rental
+    assert!(env.auths().iter().all(|(address, _)| *address != employer));
+    assert_eq!(token_client.balance(&employer), 0);
+    assert_eq!(token_client.balance(&renter), 0);
+    assert_eq!(token_client.balance(&contract.address), total_amount);
+    assert_eq!(token_client.allowance(&employer, &contract.address), 0);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.payment, PaymentSource::Sponsor(employer.clone()));
+
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "rented", &owner));
+    let event: RentedEvent = data.into_val(&env);
+    assert_eq!(event.renter, renter);
+    assert_eq!(event.payer, employer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_sponsor_without_enough_allowance_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &(3000 + commission_amount));
+    token_client.approve(&employer, &contract.address, &3000, &(env.ledger().sequence() + 100));
+
+    contract.set_sponsored_renter(&employer, &renter, &true);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Sponsor(employer.clone()), &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_sponsor_allowance_expired_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let total_amount = 3000 + commission_amount;
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &total_amount);
+    token_client.approve(&employer, &contract.address, &total_amount, &(env.ledger().sequence() + 10));
+
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 11);
+    contract.set_sponsored_renter(&employer, &renter, &true);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Sponsor(employer.clone()), &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+pub fn test_unapproved_renter_cannot_spend_sponsor_allowance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let total_amount = 3000 + commission_amount;
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &total_amount);
+    token_client.approve(&employer, &contract.address, &total_amount, &(env.ledger().sequence() + 100));
+    contract.set_sponsored_renter(&employer, &renter, &true);
+
+    contract.rental(&stranger, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Sponsor(employer.clone()), &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+pub fn test_revoked_renter_cannot_spend_sponsor_allowance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let total_amount = 3000 + commission_amount;
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &total_amount);
+    token_client.approve(&employer, &contract.address, &total_amount, &(env.ledger().sequence() + 100));
+    contract.set_sponsored_renter(&employer, &renter, &true);
+    contract.set_sponsored_renter(&employer, &renter, &false);
+    assert!(!contract.is_sponsored_renter(&employer, &renter));
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Sponsor(employer.clone()), &false);
+}
@@ -0,0 +1,109 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, vec, IntoVal};
+use crate::{
+    events::payout_owner::PayoutAddressSetEvent,
+    storage::types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_set_payout_address_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    contract.add_car(&owner, &1500_i128, &1_000_000_000_i128, &1, &30);
+
+    assert_eq!(contract.get_payout_address(&owner), owner);
+
+    contract.set_payout_address(&owner, &treasury);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_payout_address(&owner), treasury);
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "payout_address_set", &owner));
+
+    let event: PayoutAddressSetEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        PayoutAddressSetEvent {
+            owner: owner.clone(),
+            previous: owner.clone(),
+            beneficiary: treasury.clone(),
+        }
+    );
+
+    // Pointing back to the owner clears the override
+    contract.set_payout_address(&owner, &owner);
+    assert_eq!(contract.get_payout_address(&owner), owner);
+}
+
+#[test]
+pub fn test_every_payout_path_pays_the_payout_address() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &(3 * (3000 + commission_amount)));
+
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_payout_address(&owner, &treasury);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+    contract.payout_owner(&owner, &1000);
+    assert_eq!(token_client.balance(&treasury), 1000);
+
+    contract.payout_owner_all(&owner);
+    assert_eq!(token_client.balance(&treasury), 3000);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+    contract.batch_payout(&vec![&env, owner.clone()]);
+    assert_eq!(token_client.balance(&treasury), 6000);
+
+    assert_eq!(token_client.balance(&owner), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_only_owner_can_set_payout_address() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.add_car(&owner, &1500_i128, &1_000_000_000_i128, &1, &30);
+
+    env.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &contract.address,
+            fn_name: "set_payout_address",
+            args: (owner.clone(), attacker.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    contract.set_payout_address(&owner, &attacker);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+pub fn test_set_payout_address_car_not_found_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    contract.set_payout_address(&owner, &treasury);
+}
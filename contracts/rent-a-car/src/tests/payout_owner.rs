@@ -1,11 +1,12 @@
-use soroban_sdk::{testutils::Address as _, Address, vec, IntoVal, Symbol};
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, vec, IntoVal};
 use crate::{
+    events::payout_owner::{BatchPayoutEvent, PayoutEvent},
     storage::{
-        car::{read_car, write_car},
+        car::read_car,
         contract_balance::read_contract_balance,
-        types::car_status::CarStatus,
+        types::{car_status::CarStatus, payment_source::PaymentSource, rental_unit::RentalUnit},
     },
-    tests::config::{contract::ContractTest, utils::get_contract_events},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
 };
 
 #[test]
@@ -25,8 +26,8 @@ pub fn test_payout_owner_successfully() {
     // Mint suficiente para cubrir amount + commission_amount
     let amount_mint = amount + commission_amount + 1_000_000_i128; // Extra para seguridad
     token_admin.mint(&renter, &amount_mint);
-    contract.add_car(&owner, &price_per_day, &commission_amount);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
 
     // Use fixed commission amount
     let admin_fee = commission_amount;
@@ -34,12 +35,8 @@ pub fn test_payout_owner_successfully() {
     let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
     assert_eq!(contract_balance, total_balance);
 
-    // Return the car (change status to Available) so owner can withdraw
-    env.as_contract(&contract.address, || {
-        let mut car = read_car(&env, &owner);
-        car.car_status = CarStatus::Available;
-        write_car(&env, &owner, &car);
-    });
+    // Returning the car settles the rental earnings
+    contract.return_car(&renter, &owner);
 
     contract.payout_owner(&owner, &amount);
     let contract_events = get_contract_events(&env, &contract.address);
@@ -52,25 +49,26 @@ pub fn test_payout_owner_successfully() {
     let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
     assert_eq!(contract_balance, admin_fee);
     
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "payout", &owner));
+
+    let event: PayoutEvent = data.into_val(&env);
     assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "payout").as_val(),
-                    owner.clone().into_val(&env),
-                ],
-                amount.into_val(&env)
-            )
-        ]
+        event,
+        PayoutEvent {
+            owner: owner.clone(),
+            beneficiary: owner.clone(),
+            amount,
+            available_before: amount,
+            available_after: 0,
+        }
     );
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #8)")]
+#[should_panic(expected = "Error(Contract, #32)")]
 pub fn test_payout_owner_insufficient_balance_fails() {
     let ContractTest { env, contract, token, .. } = ContractTest::setup();
     env.mock_all_auths();
@@ -87,17 +85,187 @@ pub fn test_payout_owner_insufficient_balance_fails() {
     // Mint suficiente para cubrir rental_amount + commission_amount
     let amount_mint = rental_amount + commission_amount + 1_000_000_i128; // Extra para seguridad
     token_admin.mint(&renter, &amount_mint);
-    contract.add_car(&owner, &price_per_day, &commission_amount);
-    contract.rental(&renter, &owner, &total_days, &rental_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &rental_amount, &None, &PaymentSource::Renter, &false);
 
-    // Return the car (change status to Available) so we can test withdrawal
-    env.as_contract(&contract.address, || {
-        let mut car = read_car(&env, &owner);
-        car.car_status = CarStatus::Available;
-        write_car(&env, &owner, &car);
-    });
+    // Return the car so the rental earnings are settled
+    contract.return_car(&renter, &owner);
 
     // Intentar retirar más de lo disponible
     let withdraw_amount = 5000_i128; // Mayor que rental_amount (4500)
     contract.payout_owner(&owner, &withdraw_amount);
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn test_payout_owner_settled_funds_while_car_is_rented() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter1 = Address::generate(&env);
+    let renter2 = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+
+    let (token_client, token_admin, _) = token;
+
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let amount_mint = amount + commission_amount;
+    token_admin.mint(&renter1, &amount_mint);
+    token_admin.mint(&renter2, &amount_mint);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+
+    contract.rental(&renter1, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter1, &owner);
+    contract.rental(&renter2, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
+
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Rented);
+    assert_eq!(contract.get_car_balances(&owner), (amount, amount));
+
+    contract.payout_owner(&owner, &amount);
+
+    assert_eq!(token_client.balance(&owner), amount);
+    assert_eq!(contract.get_car_balances(&owner), (0, amount));
+
+    contract.return_car(&renter2, &owner);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner));
+    assert_eq!(car.available_to_withdraw, amount);
+    assert_eq!(car.pending_to_withdraw, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_payout_owner_pending_funds_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+
+    let (_, token_admin, _) = token;
+
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    token_admin.mint(&renter, &(amount + commission_amount));
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
+
+    // Earnings of the ongoing rental are still pending
+    contract.payout_owner(&owner, &amount);
+}
+
+#[test]
+pub fn test_payout_owner_all_withdraws_full_settled_balance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+
+    let (token_client, token_admin, _) = token;
+
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    token_admin.mint(&renter, &(amount + commission_amount));
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+
+    assert_eq!(contract.payout_owner_all(&owner), amount);
+    assert_eq!(token_client.balance(&owner), amount);
+    assert_eq!(contract.get_car_balances(&owner), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_payout_owner_all_with_zero_balance_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1500_i128, &1_000_000_000_i128, &1, &30);
+
+    contract.payout_owner_all(&owner);
+}
+
+#[test]
+pub fn test_batch_payout_pays_owners_and_skips_the_rest() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let idle_owner = Address::generate(&env);
+    let delisted_owner = Address::generate(&env);
+    let renter1 = Address::generate(&env);
+    let renter2 = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter1, &(3000 + commission_amount));
+    token_admin.mint(&renter2, &(5000 + commission_amount));
+
+    contract.add_car(&owner1, &1000_i128, &commission_amount, &1, &30);
+    contract.add_car(&owner2, &2500_i128, &commission_amount, &1, &30);
+    contract.add_car(&idle_owner, &1000_i128, &commission_amount, &1, &30);
+    contract.add_car(&delisted_owner, &1000_i128, &commission_amount, &1, &30);
+    contract.remove_car(&delisted_owner);
+
+    contract.rental(&renter1, &owner1, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter1, &owner1);
+    contract.rental(&renter2, &owner2, &RentalUnit::Day, &2, &5000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter2, &owner2);
+
+    let owners = vec![&env, owner1.clone(), idle_owner.clone(), delisted_owner.clone(), owner2.clone()];
+    assert_eq!(contract.batch_payout(&owners), 8000);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&owner1), 3000);
+    assert_eq!(token_client.balance(&owner2), 5000);
+    assert_eq!(token_client.balance(&idle_owner), 0);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    assert_eq!(contract_balance, 2 * commission_amount);
+
+    let (event_contract, topics, data) = contract_events.last().unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "batch_payout", &admin));
+
+    let event: BatchPayoutEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        BatchPayoutEvent {
+            admin: admin.clone(),
+            owners_paid: 2,
+            owners_skipped: 2,
+            total_amount: 8000,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_batch_payout() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let owners = vec![&env, Address::generate(&env)];
+
+    env.mock_auths(&[MockAuth {
+        address: &fake_admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract.address,
+            fn_name: "batch_payout",
+            args: (owners.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    contract.batch_payout(&owners);
+}
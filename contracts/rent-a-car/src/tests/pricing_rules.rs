@@ -0,0 +1,141 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Vec};
+use crate::{
+    events::pricing::PricingRulesSetEvent,
+    storage::types::{pricing_rule::PricingRule, payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+const DAY: u64 = 86_400;
+const MONDAY: u64 = 1_699_833_600; // 2023-11-13 00:00 UTC
+
+#[test]
+pub fn test_weekend_surcharge_applies_to_weekend_days() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(MONDAY);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    let rules = vec![&env, PricingRule::Weekend(2_000)];
+    contract.set_pricing_rules(&owner, &rules);
+    let contract_events = get_contract_events(&env, &contract.address);
+    assert_eq!(contract.get_pricing_rules(&owner), rules);
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "pricing_rules_set", &owner));
+    let event: PricingRulesSetEvent = data.into_val(&env);
+    assert_eq!(event, PricingRulesSetEvent { owner: owner.clone(), rules: rules.clone() });
+
+    // Monday to Friday at 1000, Saturday and Sunday at 1200
+    let quote = contract.quote_rental(&owner, &RentalUnit::Day, &7, &MONDAY);
+    assert_eq!(quote.list_rent, 7000);
+    assert_eq!(quote.base_rent, 7400);
+
+    token.1.mint(&renter, &quote.total);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &7, &quote.base_rent, &None, &PaymentSource::Renter, &false);
+    assert_eq!(contract.get_car_balances(&owner), (0, 7400));
+}
+
+#[test]
+pub fn test_largest_long_rental_tier_wins() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+    contract.set_pricing_rules(&owner, &vec![
+        &env,
+        PricingRule::LongRental(7, 1_000),
+        PricingRule::LongRental(30, 2_500),
+    ]);
+
+    assert_eq!(contract.quote_rental(&owner, &RentalUnit::Day, &6, &MONDAY).base_rent, 6000);
+    assert_eq!(contract.quote_rental(&owner, &RentalUnit::Day, &7, &MONDAY).base_rent, 6300);
+    assert_eq!(contract.quote_rental(&owner, &RentalUnit::Day, &30, &MONDAY).base_rent, 22_500);
+}
+
+#[test]
+pub fn test_overlapping_rules_precedence() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    // Low season from Friday to Sunday, then a later event window on Sunday
+    contract.set_pricing_rules(&owner, &vec![
+        &env,
+        PricingRule::Weekend(2_000),
+        PricingRule::Season(MONDAY + 4 * DAY, MONDAY + 7 * DAY, -1_000),
+        PricingRule::Season(MONDAY + 6 * DAY, MONDAY + 7 * DAY, 5_000),
+        PricingRule::LongRental(7, 1_000),
+    ]);
+
+    // Mon-Thu 4 x 1000, Fri and Sat 900 (season beats weekend), Sun 1500
+    // (last listed season wins), then 10% off the 7 day total
+    let quote = contract.quote_rental(&owner, &RentalUnit::Day, &7, &MONDAY);
+    assert_eq!(quote.list_rent, 7000);
+    assert_eq!(quote.base_rent, 7300 - 730);
+}
+
+#[test]
+pub fn test_clearing_rules_restores_flat_pricing() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+    contract.set_pricing_rules(&owner, &vec![&env, PricingRule::Weekend(2_000)]);
+    contract.set_pricing_rules(&owner, &Vec::new(&env));
+
+    assert_eq!(contract.get_pricing_rules(&owner).len(), 0);
+    assert_eq!(contract.quote_rental(&owner, &RentalUnit::Day, &7, &MONDAY).base_rent, 7000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+pub fn test_rental_at_flat_price_with_surcharge_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(MONDAY);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_pricing_rules(&owner, &vec![&env, PricingRule::Weekend(2_000)]);
+
+    token.1.mint(&renter, &(7000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &7, &7000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #64)")]
+pub fn test_season_with_empty_window_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+    contract.set_pricing_rules(&owner, &vec![&env, PricingRule::Season(MONDAY, MONDAY, 1_000)]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #65)")]
+pub fn test_too_many_pricing_rules_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    let mut rules = Vec::new(&env);
+    for days in 1..=9 {
+        rules.push_back(PricingRule::LongRental(days, 100));
+    }
+    contract.set_pricing_rules(&owner, &rules);
+}
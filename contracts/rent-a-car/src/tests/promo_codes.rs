@@ -0,0 +1,161 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, BytesN, Env, IntoVal};
+use crate::{
+    events::promo::PromoRedeemedEvent,
+    storage::types::{promo_discount::PromoDiscount, payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+const NOW: u64 = 1_700_000_000;
+const DAY: u64 = 86_400;
+
+fn code(env: &Env, value: &str) -> Bytes {
+    Bytes::from_slice(env, value.as_bytes())
+}
+
+fn code_hash(env: &Env, value: &str) -> BytesN<32> {
+    env.crypto().sha256(&code(env, value)).into()
+}
+
+#[test]
+pub fn test_promo_discount_is_taken_from_commission() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.create_promo_code(&code_hash(&env, "SPRING"), &PromoDiscount::Bps(1_000), &100, &(NOW + DAY), &None);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &Some(code(&env, "SPRING")), &PaymentSource::Renter, &false);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    // 10% of the 3000 rent comes off the platform fee, the owner is unaffected
+    assert_eq!(token_client.balance(&renter), 300);
+    assert_eq!(contract.get_car_balances(&owner), (0, 3000));
+    assert_eq!(contract.get_admin_fees_balance(), commission_amount - 300);
+    assert_eq!(contract.get_promo_code(&code_hash(&env, "SPRING")).unwrap().uses, 1);
+
+    assert_eq!(contract_events.len(), 2);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "promo_redeemed", &renter));
+    let event: PromoRedeemedEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        PromoRedeemedEvent {
+            owner: owner.clone(),
+            renter: renter.clone(),
+            code_hash: code_hash(&env, "SPRING"),
+            discount: 300,
+            uses: 1,
+        }
+    );
+}
+
+#[test]
+pub fn test_fixed_discount_is_capped_at_commission() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.create_promo_code(&code_hash(&env, "FRIEND"), &PromoDiscount::Fixed(500), &100, &(NOW + DAY), &None);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &Some(code(&env, "FRIEND")), &PaymentSource::Renter, &false);
+
+    assert_eq!(token_client.balance(&renter), 100);
+    assert_eq!(contract.get_car_balances(&owner), (0, 3000));
+    assert_eq!(contract.get_admin_fees_balance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #73)")]
+pub fn test_expired_promo_code_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.create_promo_code(&code_hash(&env, "SPRING"), &PromoDiscount::Bps(1_000), &100, &(NOW + DAY), &None);
+
+    env.ledger().set_timestamp(NOW + DAY);
+    token.1.mint(&renter, &(3000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &Some(code(&env, "SPRING")), &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #74)")]
+pub fn test_exhausted_promo_code_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.add_car(&other_owner, &1000_i128, &commission_amount, &1, &30);
+    contract.create_promo_code(&code_hash(&env, "ONCE"), &PromoDiscount::Fixed(10), &1, &(NOW + DAY), &None);
+
+    token.1.mint(&renter, &(2 * (3000 + commission_amount)));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &Some(code(&env, "ONCE")), &PaymentSource::Renter, &false);
+    contract.rental(&renter, &other_owner, &RentalUnit::Day, &3, &3000, &Some(code(&env, "ONCE")), &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #75)")]
+pub fn test_per_renter_limit_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.create_promo_code(&code_hash(&env, "WELCOME"), &PromoDiscount::Fixed(10), &100, &(NOW + 7 * DAY), &Some(1));
+
+    token.1.mint(&renter, &(2 * (1000 + commission_amount)));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &1, &1000, &Some(code(&env, "WELCOME")), &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &1, &1000, &Some(code(&env, "WELCOME")), &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #72)")]
+pub fn test_disabled_promo_code_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.create_promo_code(&code_hash(&env, "SPRING"), &PromoDiscount::Bps(1_000), &100, &(NOW + DAY), &None);
+    contract.disable_promo_code(&code_hash(&env, "SPRING"));
+
+    token.1.mint(&renter, &(3000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &Some(code(&env, "SPRING")), &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #70)")]
+pub fn test_create_promo_code_above_full_discount_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    contract.create_promo_code(&code_hash(&env, "FREE"), &PromoDiscount::Bps(10_001), &100, &(NOW + DAY), &None);
+}
@@ -0,0 +1,113 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::{
+    storage::{structs::quote::Quote, types::{payment_source::PaymentSource, rental_unit::RentalUnit}},
+    tests::config::contract::ContractTest,
+};
+
+#[test]
+pub fn test_quote_rental_breaks_down_costs() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    let total_days = 3;
+    let start = 1_700_000_000_u64;
+
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+
+    let quote = contract.quote_rental(&owner, &RentalUnit::Day, &total_days, &start);
+
+    assert_eq!(
+        quote,
+        Quote {
+            start,
+            end: start + 3 * 86_400,
+            list_rent: 4500,
+            base_rent: 4500,
+            commission: commission_amount,
+            deposit: 0,
+            discount: 0,
+            tax: 0,
+            total: 4500 + commission_amount,
+        }
+    );
+}
+
+#[test]
+pub fn test_quote_rental_matches_amount_charged_by_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let commission_amount = 1_000_000_000_i128;
+    let total_days = 3;
+
+    let (token_client, token_admin, _) = token;
+    let amount_mint = 10_000_000_000_i128;
+    token_admin.mint(&renter, &amount_mint);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+
+    let quote = contract.quote_rental(&owner, &RentalUnit::Day, &total_days, &env.ledger().timestamp());
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &quote.base_rent, &None, &PaymentSource::Renter, &false);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - quote.total);
+    assert_eq!(token_client.balance(&contract.address), quote.total);
+}
+
+#[test]
+pub fn test_rental_with_higher_maximum_charges_only_the_quote() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let commission_amount = 1_000_000_000_i128;
+    let total_days = 3;
+
+    let (token_client, token_admin, _) = token;
+    let amount_mint = 10_000_000_000_i128;
+    token_admin.mint(&renter, &amount_mint);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+
+    let quote = contract.quote_rental(&owner, &RentalUnit::Day, &total_days, &env.ledger().timestamp());
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &(quote.base_rent + 500), &None, &PaymentSource::Renter, &false);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - quote.total);
+    assert_eq!(contract.get_car_balances(&owner), (0, quote.base_rent));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+pub fn test_rental_with_amount_below_quote_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let commission_amount = 1_000_000_000_i128;
+    let total_days = 3;
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_000_000_i128);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+
+    // Renter tries to pay for a single day while booking three
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &price_per_day, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+pub fn test_quote_rental_car_not_found_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let owner = Address::generate(&env);
+
+    contract.quote_rental(&owner, &RentalUnit::Day, &3, &0);
+}
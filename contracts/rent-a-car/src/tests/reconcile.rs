@@ -0,0 +1,172 @@
+extern crate std;
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, vec};
+use crate::{
+    storage::{structs::revenue_share::RevenueShare, types::{payment_source::PaymentSource, rental_unit::RentalUnit}},
+    tests::config::contract::ContractTest,
+};
+
+#[test]
+pub fn test_reconcile_reports_balances_by_bucket() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(2 * (3000 + commission_amount)));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    let report = contract.reconcile();
+    assert_eq!(report.owner_balances, 3000);
+    assert_eq!(report.deposits, 3000);
+    assert_eq!(report.admin_fees_balance, 2 * commission_amount);
+    assert_eq!(report.liabilities, 6000 + 2 * commission_amount);
+    assert_eq!(report.tracked_balance, report.liabilities);
+    assert_eq!(report.token_balance, report.liabilities);
+    assert_eq!(report.surplus, 0);
+    assert!(report.is_consistent);
+}
+
+#[test]
+pub fn test_reconcile_reports_tokens_sent_directly_as_surplus() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&contract.address, &500);
+
+    let report = contract.reconcile();
+    assert_eq!(report.tracked_balance, 0);
+    assert_eq!(report.token_balance, 500);
+    assert_eq!(report.surplus, 500);
+    assert!(report.is_consistent);
+}
+
+// Small deterministic PRNG so failures can be reproduced from the seed
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound
+    }
+}
+
+#[test]
+pub fn test_accounting_invariant_holds_for_random_operation_sequences() {
+    let mut rentals = 0;
+
+    for seed in 1..=8_u64 {
+        let ContractTest { env, contract, token, .. } = ContractTest::setup();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_700_000_000);
+
+        let mut rng = XorShift(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let (_, token_admin, _) = token;
+
+        let owners = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        let renters = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        for renter in renters.iter() {
+            token_admin.mint(renter, &1_000_000_000_000_i128);
+        }
+        contract.set_insurance_plan(&500, &20_000);
+        contract.set_tax_config(&1_500, &Address::generate(&env));
+
+        for step in 0..60 {
+            let owner = &owners[rng.next(3) as usize];
+            let renter = &renters[rng.next(3) as usize];
+
+            match rng.next(16) {
+                0 => {
+                    let price_per_day = 1 + rng.next(5_000) as i128;
+                    let commission_amount = 1 + rng.next(1_000) as i128;
+                    let _ = contract.try_add_car(owner, &price_per_day, &commission_amount, &1, &30);
+                }
+                1 => {
+                    let total_days = 1 + rng.next(10) as u32;
+                    let now = env.ledger().timestamp();
+                    let payment = if rng.next(2) == 0 { PaymentSource::Renter } else { PaymentSource::Credit };
+                    let insured = rng.next(2) == 0;
+                    if let Ok(Ok(quote)) = contract.try_quote_rental(owner, &RentalUnit::Day, &total_days, &now) {
+                        if let Ok(Ok(())) = contract.try_rental(renter, owner, &RentalUnit::Day, &total_days, &quote.base_rent, &None, &payment, &insured) {
+                            rentals += 1;
+                        }
+                    }
+                }
+                2 => {
+                    let _ = contract.try_return_car(renter, owner);
+                }
+                3 => {
+                    let _ = contract.try_settle(owner);
+                }
+                4 => {
+                    let elapsed = rng.next(3 * 86_400);
+                    env.ledger().with_mut(|ledger| ledger.timestamp += elapsed);
+                }
+                5 => {
+                    if let Ok(Ok((settled, _))) = contract.try_get_car_balances(owner) {
+                        let amount = 1 + rng.next(settled.max(1) as u64) as i128;
+                        let _ = contract.try_payout_owner(owner, &amount);
+                    }
+                }
+                6 => {
+                    let _ = contract.try_payout_owner_all(owner);
+                }
+                7 => {
+                    let amount = 1 + rng.next(1_000) as i128;
+                    let _ = contract.try_withdraw_admin_fees(&amount);
+                }
+                8 => {
+                    let _ = contract.try_remove_car(owner);
+                }
+                9 => {
+                    let amount = 1 + rng.next(20_000) as i128;
+                    let _ = contract.try_deposit_credit(renter, &amount);
+                }
+                10 => {
+                    let amount = 1 + rng.next(20_000) as i128;
+                    let _ = contract.try_withdraw_credit(renter, &amount);
+                }
+                11 => {
+                    let amount = 1 + rng.next(5_000) as i128;
+                    let _ = contract.try_pay_insurance_claim(&(1 + rng.next(8)), &amount);
+                }
+                12 => {
+                    let _ = contract.try_remit_tax();
+                }
+                13 => {
+                    let split = vec![
+                        &env,
+                        RevenueShare { beneficiary: owner.clone(), bps: 7_000 },
+                        RevenueShare { beneficiary: renter.clone(), bps: 3_000 },
+                    ];
+                    let _ = contract.try_set_revenue_shares(owner, &split);
+                }
+                14 => {
+                    let _ = contract.try_withdraw_revenue_share(owner, renter);
+                }
+                _ => {
+                    let listed = vec![&env, owners[0].clone(), owners[1].clone(), owners[2].clone()];
+                    let _ = contract.try_batch_payout(&listed);
+                }
+            }
+
+            let report = contract.reconcile();
+            assert!(report.is_consistent, "seed {} step {}: {:?}", seed, step, report);
+            assert_eq!(report.tracked_balance, report.token_balance, "seed {} step {}", seed, step);
+            assert_eq!(report.surplus, 0, "seed {} step {}", seed, step);
+        }
+    }
+
+    // Make sure the sequences actually move funds around
+    assert!(rentals > 0);
+}
@@ -1,5 +1,9 @@
-use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address, vec, Symbol};
-use crate::{storage::car::has_car, tests::config::{contract::ContractTest, utils::get_contract_events}};
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::{
+    events::remove_car::CarRemovedEvent,
+    storage::{car::has_car, types::{payment_source::PaymentSource, rental_unit::RentalUnit}},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
 
 #[test]
 pub fn test_remove_car_deletes_from_storage() {
@@ -11,7 +15,7 @@ pub fn test_remove_car_deletes_from_storage() {
     let price_per_day = 1500_i128;
     let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
 
-    contract.add_car(&owner, &price_per_day, &commission_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
     assert!(env.as_contract(&contract.address, || {
         has_car(&env, &owner)
     }));
@@ -23,33 +27,37 @@ pub fn test_remove_car_deletes_from_storage() {
         has_car(&env, &owner)
     }));
 
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "car_removed", &owner));
+
+    let event: CarRemovedEvent = data.into_val(&env);
     assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "car_removed").as_val(),
-                    owner.clone().into_val(&env),
-                ],
-                ().into_val(&env)
-            )
-        ]
+        event,
+        CarRemovedEvent {
+            owner: owner.clone(),
+            token_id: 1,
+            price_per_day,
+            commission_amount,
+            paid_out: 0,
+        }
     );
 }
 
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
-pub fn test_unauthorized_user_cannot_remove_car() {
+pub fn test_non_holder_cannot_remove_car() {
     let ContractTest { env, contract, .. } = ContractTest::setup();
 
-    let fake_admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
     let owner = Address::generate(&env);
+    env.mock_all_auths();
+    contract.add_car(&owner, &1500_i128, &100_i128, &1, &30);
 
+    // Only the token holder can delist
     env.mock_auths(&[MockAuth {
-        address: &fake_admin,
+        address: &stranger,
         invoke: &MockAuthInvoke {
             contract: &contract.address,
             fn_name: "remove_car",
@@ -62,11 +70,50 @@ pub fn test_unauthorized_user_cannot_remove_car() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #2)")]
+#[should_panic(expected = "Error(Contract, #10)")]
 pub fn test_remove_car_not_found_fails() {
     let ContractTest { env, contract, .. } = ContractTest::setup();
     let owner = Address::generate(&env);
     
     env.mock_all_auths();
     contract.remove_car(&owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_remove_rented_car_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    contract.remove_car(&owner);
+}
+
+#[test]
+pub fn test_remove_car_pays_out_settled_earnings() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+
+    contract.remove_car(&owner);
+
+    assert_eq!(token_client.balance(&owner), 3000);
+    assert!(contract.reconcile().is_consistent);
 }
\ No newline at end of file
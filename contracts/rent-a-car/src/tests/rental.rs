@@ -1,12 +1,13 @@
-use soroban_sdk::{testutils::Address as _, Address, vec, IntoVal, Symbol};
+use soroban_sdk::{testutils::Address as _, Address, IntoVal};
 use crate::{
     storage::{
         car::read_car,
         contract_balance::read_contract_balance,
         rental::read_rental,
-        types::car_status::CarStatus,
+        types::{car_status::CarStatus, payment_source::PaymentSource, rental_unit::RentalUnit},
     },
-    tests::config::{contract::ContractTest, utils::get_contract_events},
+    events::rental::RentedEvent,
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
 };
 
 
@@ -27,12 +28,12 @@ pub fn test_rental_car_successfully() {
     // Mint suficiente para cubrir amount + commission_amount
     let amount_mint = amount + commission_amount + 1_000_000_i128; // Extra para seguridad
     token_admin.mint(&renter, &amount_mint);
-    contract.add_car(&owner, &price_per_day, &commission_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
 
     let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
     assert_eq!(initial_contract_balance, 0);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
     let contract_events = get_contract_events(&env, &contract.address);
 
     // Contract balance includes deposit + admin fee (fixed commission amount)
@@ -43,29 +44,40 @@ pub fn test_rental_car_successfully() {
 
     let car = env.as_contract(&contract.address, || read_car(&env, &owner));
     assert_eq!(car.car_status, CarStatus::Rented);
-    assert_eq!(car.available_to_withdraw, amount);
+    assert_eq!(car.available_to_withdraw, 0);
+    assert_eq!(car.pending_to_withdraw, amount);
 
     let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
-    assert_eq!(rental.total_days_to_rent, total_days);
+    assert_eq!(rental.unit, RentalUnit::Day);
+    assert_eq!(rental.units, total_days);
+    assert_eq!(rental.duration, 3 * 86_400);
     assert_eq!(rental.amount, amount);
     
-    // Event includes total amount (deposit + fee)
     let total_amount = amount + admin_fee;
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "rented", &owner));
+
+    // Event breaks the fee out of the total amount
+    let event: RentedEvent = data.into_val(&env);
     assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "rented").as_val(),
-                    renter.clone().into_val(&env),
-                    owner.clone().into_val(&env),
-                ],
-                (total_days, total_amount).into_val(&env)
-            )
-        ]
+        event,
+        RentedEvent {
+            owner: owner.clone(),
+            renter: renter.clone(),
+            payer: renter.clone(),
+            unit: RentalUnit::Day,
+            units: total_days,
+            start_time: 0,
+            end_time: 3 * 86_400,
+            base_rent: amount,
+            commission: admin_fee,
+            tax: 0,
+            total_amount,
+            pending_before: 0,
+            pending_after: amount,
+        }
     );
 }
 
@@ -89,11 +101,11 @@ pub fn test_rental_car_already_rented_fails() {
     let amount_mint: i128 = amount + commission_amount + 1_000_000_i128; // Extra para seguridad
     token_admin.mint(&renter1, &amount_mint);
     token_admin.mint(&renter2, &amount_mint);
-    contract.add_car(&owner, &price_per_day, &commission_amount);
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
 
     // Primer renter alquila el carro exitosamente
-    contract.rental(&renter1, &owner, &total_days, &amount);
+    contract.rental(&renter1, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
 
     // Segundo renter intenta alquilar el mismo carro que ya está rentado
-    contract.rental(&renter2, &owner, &total_days, &amount);
+    contract.rental(&renter2, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+use soroban_sdk::{testutils::Address as _, Address, IntoVal};
+use crate::{
+    events::rental_limits::RentalLimitsSetEvent,
+    storage::types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+#[should_panic(expected = "Error(Contract, #61)")]
+pub fn test_rental_shorter_than_min_days_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &3, &30);
+
+    token.1.mint(&renter, &(2000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &2, &2000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #62)")]
+pub fn test_rental_longer_than_max_days_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &7);
+
+    token.1.mint(&renter, &(8000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &8, &8000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+pub fn test_owner_updates_rental_limits() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &7);
+
+    contract.set_rental_limits(&owner, &2, &14);
+    let contract_events = get_contract_events(&env, &contract.address);
+    assert_eq!(contract.get_rental_limits(&owner), (2, 14));
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "rental_limits_set", &owner));
+    let event: RentalLimitsSetEvent = data.into_val(&env);
+    assert_eq!(event, RentalLimitsSetEvent { owner: owner.clone(), min_days: 2, max_days: 14 });
+
+    token.1.mint(&renter, &(10_000 + commission_amount));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &10, &10_000, &None, &PaymentSource::Renter, &false);
+    assert!(contract.has_rental(&renter, &owner));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #60)")]
+pub fn test_set_rental_limits_with_zero_min_days_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &7);
+    contract.set_rental_limits(&owner, &0, &7);
+}
@@ -0,0 +1,180 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, IntoVal};
+use crate::{
+    events::reputation::RatingSubmittedEvent,
+    storage::{structs::reputation::Reputation, types::{payment_source::PaymentSource, rental_unit::RentalUnit}},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+const DAY: u64 = 86_400;
+
+fn rent_and_return<'a>(test: &ContractTest<'a>, owner: &Address, renter: &Address, days_late: u64) {
+    let ContractTest { env, contract, token, .. } = test;
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    token.1.mint(renter, &(3000 + commission_amount));
+    contract.rental(renter, owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    env.ledger().with_mut(|ledger| ledger.timestamp += 3 * DAY + days_late * DAY);
+    contract.return_car(renter, owner);
+}
+
+#[test]
+pub fn test_owner_and_renter_rate_each_other() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let ContractTest { env, contract, .. } = &test;
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+    rent_and_return(&test, &owner, &renter, 0);
+
+    contract.rate_renter(&owner, &renter, &4);
+    let contract_events = get_contract_events(env, &contract.address);
+    contract.rate_owner(&renter, &owner, &5);
+
+    assert_eq!(
+        contract.get_reputation(&renter),
+        Reputation { rating_count: 1, rating_sum: 4, late_returns: 0, disputes_lost: 0 }
+    );
+    assert_eq!(
+        contract.get_reputation(&owner),
+        Reputation { rating_count: 1, rating_sum: 5, late_returns: 0, disputes_lost: 0 }
+    );
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(env, "rating_submitted", &owner));
+    let event: RatingSubmittedEvent = data.into_val(env);
+    assert_eq!(
+        event,
+        RatingSubmittedEvent {
+            owner: owner.clone(),
+            renter: renter.clone(),
+            rater: owner.clone(),
+            rated: renter.clone(),
+            score: 4,
+            rating_count: 1,
+            rating_sum: 4,
+        }
+    );
+}
+
+#[test]
+pub fn test_late_return_is_recorded_in_renter_reputation() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let ContractTest { env, contract, .. } = &test;
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    rent_and_return(&test, &owner, &renter, 0);
+    assert_eq!(contract.get_reputation(&renter).late_returns, 0);
+
+    rent_and_return(&test, &owner, &renter, 1);
+    assert_eq!(contract.get_reputation(&renter).late_returns, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+pub fn test_rating_without_completed_rental_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    contract.rate_renter(&owner, &renter, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_rating_twice_for_the_same_rental_fails() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let ContractTest { env, contract, .. } = &test;
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+    rent_and_return(&test, &owner, &renter, 0);
+
+    contract.rate_owner(&renter, &owner, &5);
+    contract.rate_owner(&renter, &owner, &5);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+pub fn test_rating_out_of_range_fails() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let ContractTest { env, contract, .. } = &test;
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+    rent_and_return(&test, &owner, &renter, 0);
+
+    contract.rate_renter(&owner, &renter, &6);
+}
+
+#[test]
+pub fn test_min_renter_score_admits_well_rated_renters() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let ContractTest { env, contract, .. } = &test;
+
+    let owner = Address::generate(env);
+    let other_owner = Address::generate(env);
+    let renter = Address::generate(env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+    contract.add_car(&other_owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    rent_and_return(&test, &other_owner, &renter, 0);
+    contract.rate_renter(&other_owner, &renter, &4);
+
+    contract.set_min_renter_score(&owner, &400);
+
+    test.token.1.mint(&renter, &(3000 + 1_000_000_000_i128));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    assert!(contract.has_rental(&renter, &owner));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #53)")]
+pub fn test_min_renter_score_rejects_low_rated_renters() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let ContractTest { env, contract, .. } = &test;
+
+    let owner = Address::generate(env);
+    let other_owner = Address::generate(env);
+    let renter = Address::generate(env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+    contract.add_car(&other_owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+
+    rent_and_return(&test, &other_owner, &renter, 0);
+    contract.rate_renter(&other_owner, &renter, &2);
+
+    contract.set_min_renter_score(&owner, &350);
+
+    test.token.1.mint(&renter, &(3000 + 1_000_000_000_i128));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #53)")]
+pub fn test_min_renter_score_rejects_unrated_renters() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &1_000_000_000_i128, &1, &30);
+    contract.set_min_renter_score(&owner, &100);
+
+    token.1.mint(&renter, &(3000 + 1_000_000_000_i128));
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
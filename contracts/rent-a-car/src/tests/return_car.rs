@@ -0,0 +1,62 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, IntoVal};
+use crate::{
+    events::return_car::CarReturnedEvent,
+    storage::types::{car_status::CarStatus, payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_return_car_successfully() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+
+    let (_, token_admin, _) = token;
+
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    token_admin.mint(&renter, &(amount + commission_amount));
+    contract.add_car(&owner, &1500_i128, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &amount, &None, &PaymentSource::Renter, &false);
+
+    env.ledger().set_timestamp(1_700_000_000 + 86_400);
+    contract.return_car(&renter, &owner);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+    assert!(!contract.has_rental(&renter, &owner));
+
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "car_returned", &owner));
+
+    let event: CarReturnedEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        CarReturnedEvent {
+            owner: owner.clone(),
+            renter: renter.clone(),
+            returned_at: 1_700_000_000 + 86_400,
+            settled_amount: amount,
+            available_before: 0,
+            available_after: amount,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+pub fn test_return_car_without_rental_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1500_i128, &1_000_000_000_i128, &1, &30);
+
+    contract.return_car(&renter, &owner);
+}
@@ -0,0 +1,205 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Env, IntoVal, Vec};
+use crate::{
+    events::revenue::{RevenueSplitEvent, ShareWithdrawnEvent},
+    storage::{
+        structs::revenue_share::RevenueShare,
+        types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    },
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+fn shares(env: &Env, split: &[(&Address, u32)]) -> Vec<RevenueShare> {
+    let mut shares = Vec::new(env);
+    for (beneficiary, bps) in split.iter() {
+        shares.push_back(RevenueShare { beneficiary: (*beneficiary).clone(), bps: *bps });
+    }
+    shares
+}
+
+#[test]
+pub fn test_returned_rental_is_split_between_beneficiaries() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    let split = shares(&env, &[(&owner, 6_000), (&investor, 4_000)]);
+    contract.set_revenue_shares(&owner, &split);
+    assert_eq!(contract.get_revenue_shares(&owner), split);
+
+    token.1.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car_balances(&owner), (0, 0));
+    assert_eq!(contract.get_share_balance(&owner, &owner), 1800);
+    assert_eq!(contract.get_share_balance(&owner, &investor), 1200);
+
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "revenue_split", &owner));
+    let event: RevenueSplitEvent = data.into_val(&env);
+    assert_eq!(event, RevenueSplitEvent { owner: owner.clone(), amount: 3000, shares: split });
+
+    let report = contract.reconcile();
+    assert_eq!(report.share_balances, 3000);
+    assert!(report.is_consistent);
+}
+
+#[test]
+pub fn test_settled_days_are_split_and_dust_goes_to_first_beneficiary() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1001_i128, &100_i128, &1, &30);
+    contract.set_revenue_shares(&owner, &shares(&env, &[(&a, 3_334), (&b, 3_333), (&c, 3_333)]));
+
+    token.1.mint(&renter, &3103);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3003, &None, &PaymentSource::Renter, &false);
+    env.ledger().set_timestamp(86_400);
+    assert_eq!(contract.settle(&owner), 1001);
+
+    assert_eq!(contract.get_car_balances(&owner), (0, 2002));
+    assert_eq!(contract.get_share_balance(&owner, &a), 335);
+    assert_eq!(contract.get_share_balance(&owner, &b), 333);
+    assert_eq!(contract.get_share_balance(&owner, &c), 333);
+    assert!(contract.reconcile().is_consistent);
+}
+
+#[test]
+pub fn test_beneficiary_withdraws_share() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_revenue_shares(&owner, &shares(&env, &[(&owner, 5_000), (&investor, 5_000)]));
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+
+    assert_eq!(contract.withdraw_revenue_share(&owner, &investor), 1500);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&investor), 1500);
+    assert_eq!(contract.get_share_balance(&owner, &investor), 0);
+
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "share_withdrawn", &investor));
+    let event: ShareWithdrawnEvent = data.into_val(&env);
+    assert_eq!(event, ShareWithdrawnEvent {
+        owner: owner.clone(),
+        beneficiary: investor.clone(),
+        paid_to: investor.clone(),
+        amount: 1500,
+    });
+
+    let report = contract.reconcile();
+    assert_eq!(report.share_balances, 1500);
+    assert_eq!(report.tracked_balance, report.token_balance);
+    assert!(report.is_consistent);
+}
+
+#[test]
+pub fn test_clearing_shares_pays_owner_again() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_revenue_shares(&owner, &shares(&env, &[(&investor, 10_000)]));
+    contract.set_revenue_shares(&owner, &vec![&env]);
+
+    token.1.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+
+    assert_eq!(contract.get_car_balances(&owner), (3000, 0));
+    assert_eq!(contract.get_share_balance(&owner, &investor), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_shares_not_adding_up_fail() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    contract.set_revenue_shares(&owner, &shares(&env, &[(&owner, 6_000), (&investor, 3_000)]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_duplicate_beneficiary_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    contract.set_revenue_shares(&owner, &shares(&env, &[(&owner, 5_000), (&owner, 5_000)]));
+}
+
+#[test]
+pub fn test_owner_share_goes_to_payout_address() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_payout_address(&owner, &treasury);
+    contract.set_revenue_shares(&owner, &shares(&env, &[(&owner, 5_000), (&investor, 5_000)]));
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+
+    assert_eq!(contract.withdraw_revenue_share(&owner, &owner), 1500);
+
+    assert_eq!(token_client.balance(&owner), 0);
+    assert_eq!(token_client.balance(&treasury), 1500);
+}
+
+#[test]
+pub fn test_insurance_claim_is_split_between_beneficiaries() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_revenue_shares(&owner, &shares(&env, &[(&owner, 6_000), (&investor, 4_000)]));
+    contract.set_insurance_plan(&500, &2000);
+
+    token.1.mint(&renter, &4600);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+    contract.return_car(&renter, &owner);
+    contract.pay_insurance_claim(&1, &1000);
+
+    // Nothing is left for the owner to take past the co-owners
+    assert_eq!(contract.get_car_balances(&owner), (0, 0));
+    assert_eq!(contract.get_share_balance(&owner, &owner), 1800 + 600);
+    assert_eq!(contract.get_share_balance(&owner, &investor), 1200 + 400);
+    assert!(contract.reconcile().is_consistent);
+}
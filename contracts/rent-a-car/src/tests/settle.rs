@@ -0,0 +1,110 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, IntoVal};
+use crate::{
+    events::settle::RentalSettledEvent,
+    storage::{car::read_car, rental::read_rental, types::{payment_source::PaymentSource, rental_unit::RentalUnit}},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_settle_moves_accrued_days_to_owner() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+
+    let (_, token_admin, _) = token;
+
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    token_admin.mint(&renter, &(amount + commission_amount));
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
+
+    // Less than a full day has elapsed: nothing accrued yet
+    env.ledger().set_timestamp(1_700_000_000 + DAY - 1);
+    assert_eq!(contract.settle(&owner), 0);
+    assert_eq!(contract.get_car_balances(&owner), (0, amount));
+
+    env.ledger().set_timestamp(1_700_000_000 + DAY);
+    assert_eq!(contract.settle(&owner), 1500);
+    let contract_events = get_contract_events(&env, &contract.address);
+    assert_eq!(contract.get_car_balances(&owner), (1500, 3000));
+
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "rental_settled", &owner));
+
+    let event: RentalSettledEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        RentalSettledEvent {
+            owner: owner.clone(),
+            renter: renter.clone(),
+            amount: 1500,
+            settled_before: 0,
+            settled_after: 1500,
+            available_after: 1500,
+            pending_after: 3000,
+        }
+    );
+
+    // Settling twice in the same day is a no-op
+    assert_eq!(contract.settle(&owner), 0);
+
+    // Past the end of the rental everything is settled
+    env.ledger().set_timestamp(1_700_000_000 + 10 * DAY);
+    assert_eq!(contract.settle(&owner), 3000);
+    assert_eq!(contract.get_car_balances(&owner), (amount, 0));
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.settled_amount, amount);
+}
+
+#[test]
+pub fn test_return_car_after_partial_settlement_settles_remaining_escrow() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1000_i128;
+    let total_days = 7;
+    let amount = 7000_i128;
+
+    let (_, token_admin, _) = token;
+
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+    token_admin.mint(&renter, &(amount + commission_amount));
+    contract.add_car(&owner, &price_per_day, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &total_days, &amount, &None, &PaymentSource::Renter, &false);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp += 2 * DAY);
+    contract.settle(&owner);
+    contract.payout_owner(&owner, &2000);
+
+    contract.return_car(&renter, &owner);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner));
+    assert_eq!(car.available_to_withdraw, 5000);
+    assert_eq!(car.pending_to_withdraw, 0);
+    assert_eq!(car.current_renter, None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_settle_car_not_rented_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1500_i128, &1_000_000_000_i128, &1, &30);
+
+    contract.settle(&owner);
+}
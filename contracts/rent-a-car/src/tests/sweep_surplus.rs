@@ -0,0 +1,107 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, IntoVal};
+use crate::{
+    events::contract::SurplusSweptEvent,
+    storage::types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{
+        contract::ContractTest,
+        utils::{create_token_contract, event_topics, get_contract_events},
+    },
+};
+
+#[test]
+pub fn test_sweep_surplus_only_moves_untracked_tokens() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let recovery = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    // Tokens sent by mistake, outside of `rental`
+    token_admin.mint(&contract.address, &700);
+
+    assert_eq!(contract.sweep_surplus(&token_client.address, &recovery), 700);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&recovery), 700);
+    assert_eq!(token_client.balance(&contract.address), 3000 + commission_amount);
+
+    let report = contract.reconcile();
+    assert_eq!(report.surplus, 0);
+    assert!(report.is_consistent);
+
+    assert_eq!(contract_events.len(), 1);
+    let (event_contract, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(event_contract, contract.address);
+    assert_eq!(topics, event_topics(&env, "surplus_swept", &admin));
+
+    let event: SurplusSweptEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        SurplusSweptEvent {
+            admin: admin.clone(),
+            token: token_client.address.clone(),
+            to: recovery.clone(),
+            amount: 700,
+        }
+    );
+}
+
+#[test]
+pub fn test_sweep_surplus_of_foreign_token_moves_full_balance() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let recovery = Address::generate(&env);
+    let (other_token, other_token_admin) = create_token_contract(&env, &Address::generate(&env));
+    other_token_admin.mint(&contract.address, &1234);
+
+    assert_eq!(contract.sweep_surplus(&other_token.address, &recovery), 1234);
+    assert_eq!(other_token.balance(&recovery), 1234);
+    assert_eq!(other_token.balance(&contract.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_sweep_surplus_without_surplus_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    contract.sweep_surplus(&token_client.address, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_sweep_surplus() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let (token_client, _, _) = token;
+
+    env.mock_auths(&[MockAuth {
+        address: &fake_admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract.address,
+            fn_name: "sweep_surplus",
+            args: (token_client.address.clone(), fake_admin.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    contract.sweep_surplus(&token_client.address, &fake_admin);
+}
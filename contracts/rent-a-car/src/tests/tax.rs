@@ -0,0 +1,103 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, IntoVal};
+use crate::{
+    events::{rental::RentedEvent, tax::TaxRemittedEvent},
+    storage::{
+        structs::tax_config::TaxConfig,
+        types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    },
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_quote_includes_tax_on_rent_and_commission() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let collector = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_tax_config(&2_100, &collector);
+    assert_eq!(contract.get_tax_config(), Some(TaxConfig { rate_bps: 2_100, collector }));
+
+    let quote = contract.quote_rental(&owner, &RentalUnit::Day, &3, &0);
+    assert_eq!(quote.tax, 651);
+    assert_eq!(quote.total, 3000 + 100 + 651);
+}
+
+#[test]
+pub fn test_rental_withholds_tax() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let collector = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_tax_config(&2_100, &collector);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &3751);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&renter), 0);
+    assert_eq!(contract.get_tax_balance(), 651);
+    assert_eq!(contract.get_admin_fees_balance(), 100);
+    assert_eq!(contract.get_car_balances(&owner), (0, 3000));
+
+    let (_, _, data) = contract_events.get(0).unwrap();
+    let event: RentedEvent = data.into_val(&env);
+    assert_eq!(event.tax, 651);
+    assert_eq!(event.total_amount, 3751);
+
+    let report = contract.reconcile();
+    assert_eq!(report.tax_balance, 651);
+    assert!(report.is_consistent);
+}
+
+#[test]
+pub fn test_remit_tax_pays_collector() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let collector = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_tax_config(&1_000, &collector);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &3410);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    // Lowering the rate to zero still lets the withheld tax go out
+    contract.set_tax_config(&0, &collector);
+    assert_eq!(contract.remit_tax(), 310);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&collector), 310);
+    assert_eq!(contract.get_tax_balance(), 0);
+
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "tax_remitted", &contract.get_admin()));
+    let event: TaxRemittedEvent = data.into_val(&env);
+    assert_eq!(event, TaxRemittedEvent { admin: contract.get_admin(), collector, amount: 310 });
+
+    let report = contract.reconcile();
+    assert_eq!(report.tracked_balance, report.token_balance);
+    assert!(report.is_consistent);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_remit_tax_with_nothing_withheld_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let collector = Address::generate(&env);
+    contract.set_tax_config(&1_000, &collector);
+
+    contract.remit_tax();
+}
@@ -0,0 +1,171 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, Address, IntoVal};
+use crate::{
+    events::transfer_car::CarTransferredEvent,
+    storage::{
+        car::has_car,
+        structs::revenue_share::RevenueShare,
+        types::{payment_source::PaymentSource, pricing_rule::PricingRule, rental_unit::RentalUnit},
+    },
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+#[test]
+pub fn test_transfer_car_moves_listing_to_new_owner() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    contract.add_car(&owner, &1500_i128, &100_i128, &2, &20);
+    contract.set_hourly_price(&owner, &80);
+    contract.set_pricing_rules(&owner, &vec![&env, PricingRule::Weekend(2_000)]);
+
+    contract.transfer_car(&owner, &buyer);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert!(!env.as_contract(&contract.address, || has_car(&env, &owner)));
+    assert_eq!(contract.get_car_info(&buyer), (1500, 0));
+    assert_eq!(contract.get_rental_limits(&buyer), (2, 20));
+    assert_eq!(contract.get_pricing_rules(&buyer), vec![&env, PricingRule::Weekend(2_000)]);
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "car_transferred", &owner));
+    let event: CarTransferredEvent = data.into_val(&env);
+    assert_eq!(event, CarTransferredEvent { owner: owner.clone(), new_owner: buyer.clone(), token_id: 1, paid_out: 0 });
+}
+
+#[test]
+pub fn test_seller_keeps_earnings_on_transfer() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    contract.return_car(&renter, &owner);
+    contract.set_revenue_shares(&owner, &vec![&env, RevenueShare { beneficiary: investor, bps: 10_000 }]);
+
+    contract.transfer_car(&owner, &buyer);
+
+    assert_eq!(token_client.balance(&owner), 3000);
+    assert_eq!(contract.get_car_balances(&buyer), (0, 0));
+    assert_eq!(contract.get_revenue_shares(&buyer).len(), 0);
+    assert!(contract.reconcile().is_consistent);
+}
+
+#[test]
+pub fn test_claim_after_transfer_pays_seller() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let wallet = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_payout_address(&owner, &wallet);
+    contract.set_insurance_plan(&500, &2000);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &4600);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+    contract.return_car(&renter, &owner);
+    contract.transfer_car(&owner, &buyer);
+
+    // The damage happened under the seller, so the buyer's balance is untouched.
+    // The sale cleared the seller's payout address, so they are paid directly.
+    contract.pay_insurance_claim(&1, &1200);
+
+    assert_eq!(token_client.balance(&wallet), 3000);
+    assert_eq!(token_client.balance(&owner), 1200);
+    assert_eq!(contract.get_car_balances(&buyer), (0, 0));
+    assert_eq!(contract.get_insurance_pool().balance, 300);
+    assert!(contract.reconcile().is_consistent);
+}
+
+#[test]
+pub fn test_claim_after_transfer_skips_sellers_next_listing() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.set_insurance_plan(&500, &2000);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &4600);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &true);
+    contract.return_car(&renter, &owner);
+    contract.transfer_car(&owner, &buyer);
+
+    // A different car listed under the seller's address has nothing to do with the claim
+    contract.add_car(&owner, &2000_i128, &100_i128, &1, &30);
+    contract.pay_insurance_claim(&1, &1200);
+
+    assert_eq!(token_client.balance(&owner), 3000 + 1200);
+    assert_eq!(contract.get_car_balances(&owner), (0, 0));
+    assert!(contract.reconcile().is_consistent);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_transfer_rented_car_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    token.1.mint(&renter, &3100);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+
+    contract.transfer_car(&owner, &buyer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+pub fn test_transfer_to_owner_with_a_car_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+    contract.add_car(&buyer, &1000_i128, &100_i128, &1, &30);
+
+    contract.transfer_car(&owner, &buyer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_transfer_car_needs_buyer_consent() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    contract.add_car(&owner, &1000_i128, &100_i128, &1, &30);
+
+    env.mock_auths(&[MockAuth {
+        address: &owner,
+        invoke: &MockAuthInvoke {
+            contract: &contract.address,
+            fn_name: "transfer_car",
+            args: (owner.clone(), buyer.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    contract.transfer_car(&owner, &buyer);
+}
@@ -0,0 +1,164 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, IntoVal};
+use crate::{
+    events::verification::RenterVerifiedEvent,
+    storage::types::{payment_source::PaymentSource, rental_unit::RentalUnit},
+    tests::config::{contract::ContractTest, utils::{event_topics, get_contract_events}},
+};
+
+const NOW: u64 = 1_700_000_000;
+const YEAR: u64 = 365 * 86_400;
+
+#[test]
+pub fn test_verified_renter_can_rent_verified_only_car() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_verified_renters_only(&owner, &true);
+
+    contract.verify_renter(&admin, &renter, &(NOW + YEAR));
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_renter_verification(&renter), Some(NOW + YEAR));
+
+    assert_eq!(contract_events.len(), 1);
+    let (_, topics, data) = contract_events.get(0).unwrap();
+    assert_eq!(topics, event_topics(&env, "renter_verification_set", &renter));
+    let event: RenterVerifiedEvent = data.into_val(&env);
+    assert_eq!(
+        event,
+        RenterVerifiedEvent {
+            renter: renter.clone(),
+            verifier: admin.clone(),
+            previous_expires_at: None,
+            expires_at: Some(NOW + YEAR),
+        }
+    );
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+    assert!(contract.has_rental(&renter, &owner));
+}
+
+#[test]
+pub fn test_attestor_can_verify_renters() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let attestor = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.set_attestor(&Some(attestor.clone()));
+    contract.verify_renter(&attestor, &renter, &(NOW + YEAR));
+    assert_eq!(contract.get_renter_verification(&renter), Some(NOW + YEAR));
+
+    contract.revoke_renter_verification(&attestor, &renter);
+    assert_eq!(contract.get_renter_verification(&renter), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+pub fn test_removed_attestor_cannot_verify_renters() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let attestor = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.set_attestor(&Some(attestor.clone()));
+    contract.set_attestor(&None);
+
+    contract.verify_renter(&attestor, &renter, &(NOW + YEAR));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+pub fn test_unknown_address_cannot_verify_renters() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let renter = Address::generate(&env);
+
+    // Renters can't verify themselves
+    contract.verify_renter(&renter, &renter, &(NOW + YEAR));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+pub fn test_verify_renter_with_past_expiry_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let renter = Address::generate(&env);
+    contract.verify_renter(&admin, &renter, &NOW);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+pub fn test_unverified_renter_cannot_rent_verified_only_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_verified_renters_only(&owner, &true);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+pub fn test_expired_verification_cannot_rent_verified_only_car() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_verified_renters_only(&owner, &true);
+    contract.verify_renter(&admin, &renter, &(NOW + 86_400));
+
+    env.ledger().set_timestamp(NOW + 86_400);
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+pub fn test_revoked_renter_cannot_rent_verified_only_car() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission_amount = 1_000_000_000_i128; // 1 XLM in stroops
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &(3000 + commission_amount));
+    contract.add_car(&owner, &1000_i128, &commission_amount, &1, &30);
+    contract.set_verified_renters_only(&owner, &true);
+    contract.verify_renter(&admin, &renter, &(NOW + YEAR));
+    contract.revoke_renter_verification(&admin, &renter);
+
+    contract.rental(&renter, &owner, &RentalUnit::Day, &3, &3000, &None, &PaymentSource::Renter, &false);
+}
@@ -2,6 +2,8 @@ import { useState, useEffect } from "react";
 import { ICar } from "../interfaces/car";
 import { CarStatus } from "../interfaces/car-status";
 import { IRentACarContract } from "../interfaces/contract.ts";
+import { IQuote } from "../interfaces/quote";
+import { RentalUnit } from "../interfaces/rental-unit";
 import { UserRole } from "../interfaces/user-role";
 import { useStellarAccounts } from "../providers/StellarAccountProvider";
 import { stellarService } from "../services/stellar.service";
@@ -293,21 +295,39 @@ export const CarsList = ({ cars }: CarsListProps) => {
       const contractClient =
         await stellarService.buildClient<IRentACarContract>(walletAddress);
 
-      // Use fixed commission amount from car
-      const commissionAmount = car.commissionAmount || 1; // Default 1 XLM if not set
-      const depositAmount = car.pricePerDay * totalDaysToRent * ONE_XLM_IN_STROOPS;
-      const adminFee = commissionAmount * ONE_XLM_IN_STROOPS; // Convert XLM to stroops
-      const totalCost = depositAmount + adminFee;
+      // The contract prices the rental with the car's pricing rules, so ask it
+      // for the quote instead of multiplying the daily price here
+      const quoteResult = contractClient.quote_rental({
+        owner: car.ownerAddress,
+        unit: { tag: RentalUnit.DAY, values: undefined },
+        units: totalDaysToRent,
+        start: Math.floor(Date.now() / 1000),
+      });
+      let quote: IQuote;
+      if (quoteResult && typeof quoteResult === 'object' && 'simulate' in quoteResult) {
+        const simulation = await (quoteResult as any).simulate();
+        quote = simulation?.result || simulation?.value || simulation;
+      } else {
+        quote = await quoteResult;
+      }
+
+      const depositAmount = Number(quote.base_rent);
+      const adminFee = Number(quote.commission);
+      const taxAmount = Number(quote.tax);
+      // The quote total already includes tax, deposit and any discount
+      const totalCost = Number(quote.total);
 
       // Show confirmation with fee breakdown
-      const feeXLM = commissionAmount;
+      const feeXLM = adminFee / ONE_XLM_IN_STROOPS;
       const depositXLM = depositAmount / ONE_XLM_IN_STROOPS;
+      const taxXLM = taxAmount / ONE_XLM_IN_STROOPS;
       const totalXLM = totalCost / ONE_XLM_IN_STROOPS;
 
       const confirmMessage = 
         `Confirmar alquiler:\n\n` +
-        `Depósito: ${depositXLM.toFixed(2)} XLM\n` +
+        `Renta: ${depositXLM.toFixed(2)} XLM\n` +
         `Comisión admin (fija): ${feeXLM.toFixed(2)} XLM\n` +
+        `Impuesto: ${taxXLM.toFixed(2)} XLM\n` +
         `Total a pagar: ${totalXLM.toFixed(2)} XLM\n\n` +
         `¿Deseas continuar?`;
 
@@ -331,8 +351,8 @@ export const CarsList = ({ cars }: CarsListProps) => {
         renter,
         owner: car.ownerAddress,
         depositAmount: depositAmount / ONE_XLM_IN_STROOPS,
-        commissionAmount: commissionAmount,
         adminFee: adminFee / ONE_XLM_IN_STROOPS,
+        tax: taxAmount / ONE_XLM_IN_STROOPS,
         totalCost: totalCost / ONE_XLM_IN_STROOPS,
         totalDaysToRent,
       });
@@ -340,8 +360,12 @@ export const CarsList = ({ cars }: CarsListProps) => {
       const result = await contractClient.rental({
         renter,
         owner: car.ownerAddress,
-        total_days_to_rent: totalDaysToRent,
-        amount: depositAmount, // Contract calculates admin fee internally
+        unit: { tag: RentalUnit.DAY, values: undefined },
+        units: totalDaysToRent,
+        amount: depositAmount, // Highest rent accepted; the contract adds the fee itself
+        promo_code: undefined,
+        payment: { tag: "Renter", values: undefined },
+        insured: false,
       });
       const xdr = result.toXDR();
 
@@ -428,46 +452,47 @@ export const CarsList = ({ cars }: CarsListProps) => {
   };
 
   const renderActionButton = (car: ICar) => {
-    if (selectedRole === UserRole.ADMIN) {
-      return (
-        <button
-          onClick={() => void handleDelete(car.ownerAddress)}
-          className="px-3 py-1 bg-red-600/80 text-white rounded font-semibold hover:bg-red-600 hover:glow-blue transition-all cursor-pointer border border-red-500/50"
-        >
-          Delete
-        </button>
-      );
-    }
-
     if (selectedRole === UserRole.OWNER) {
       // Owners can only withdraw when car is available (returned)
       const canWithdraw = car.status === CarStatus.AVAILABLE && 
                          (car.availableToWithdraw ?? 0) > 0;
       const availableAmount = car.availableToWithdraw ?? 0;
+      // Only whoever holds the car's token can delist it
+      const canDelete = car.status === CarStatus.AVAILABLE && walletAddress === car.ownerAddress;
       
       return (
-        <button
-          onClick={() => {
-            if (availableAmount > 0) {
-              void handlePayout(car.ownerAddress, availableAmount * ONE_XLM_IN_STROOPS);
+        <div className="flex gap-2">
+          <button
+            onClick={() => {
+              if (availableAmount > 0) {
+                void handlePayout(car.ownerAddress, availableAmount * ONE_XLM_IN_STROOPS);
+              }
+            }}
+            disabled={!canWithdraw}
+            className={`px-3 py-1 rounded font-semibold transition-all ${
+              canWithdraw
+                ? "bg-green-600/80 text-white hover:bg-green-600 hover:glow-blue cursor-pointer border border-green-500/50"
+                : "bg-gray-600/30 text-gray-500 cursor-not-allowed border border-gray-500/30"
+            }`}
+            title={
+              !canWithdraw
+                ? car.status !== CarStatus.AVAILABLE
+                  ? "El auto debe estar devuelto (Available) para retirar fondos"
+                  : "No hay fondos disponibles para retirar"
+                : `Retirar ${availableAmount.toFixed(2)} XLM disponibles`
             }
-          }}
-          disabled={!canWithdraw}
-          className={`px-3 py-1 rounded font-semibold transition-all ${
-            canWithdraw
-              ? "bg-green-600/80 text-white hover:bg-green-600 hover:glow-blue cursor-pointer border border-green-500/50"
-              : "bg-gray-600/30 text-gray-500 cursor-not-allowed border border-gray-500/30"
-          }`}
-          title={
-            !canWithdraw
-              ? car.status !== CarStatus.AVAILABLE
-                ? "El auto debe estar devuelto (Available) para retirar fondos"
-                : "No hay fondos disponibles para retirar"
-              : `Retirar ${availableAmount.toFixed(2)} XLM disponibles`
-          }
-        >
-          Withdraw
-        </button>
+          >
+            Withdraw
+          </button>
+          {canDelete && (
+            <button
+              onClick={() => void handleDelete(car.ownerAddress)}
+              className="px-3 py-1 bg-red-600/80 text-white rounded font-semibold hover:bg-red-600 hover:glow-blue transition-all cursor-pointer border border-red-500/50"
+            >
+              Delete
+            </button>
+          )}
+        </div>
       );
     }
 
@@ -1,5 +1,7 @@
 import type { ClientOptions } from "@stellar/stellar-sdk/contract";
 import { CarStatus } from "./car-status";
+import { IQuote } from "./quote";
+import { RentalUnit } from "./rental-unit";
 
 export interface IBaseContractClient {
   readonly options: ClientOptions;
@@ -19,26 +21,49 @@ export interface IRentACarContract extends IBaseContractClient {
     owner,
     price_per_day,
     commission_amount,
+    min_days,
+    max_days,
   }: {
     owner: string;
     price_per_day: number;
     commission_amount: number;
+    min_days: number;
+    max_days: number;
   }) => Promise<this>;
 
   get_car_status: ({ owner }: { owner: string }) => Promise<CarStatus>;
   get_car_info: ({ owner }: { owner: string }) => Promise<[number, number]>;
   has_rental: ({ renter, owner }: { renter: string; owner: string }) => Promise<boolean>;
+  quote_rental: ({
+    owner,
+    unit,
+    units,
+    start,
+  }: {
+    owner: string;
+    unit: { tag: RentalUnit; values: void };
+    units: number;
+    start: number;
+  }) => Promise<IQuote>;
 
   rental: ({
     renter,
     owner,
-    total_days_to_rent,
+    unit,
+    units,
     amount,
+    promo_code,
+    payment,
+    insured,
   }: {
     renter: string;
     owner: string;
-    total_days_to_rent: number;
+    unit: { tag: RentalUnit; values: void };
+    units: number;
     amount: number;
+    promo_code?: Uint8Array;
+    payment: { tag: "Renter" | "Credit" | "Sponsor" | "Corporate"; values: void | [string] };
+    insured: boolean;
   }) => Promise<this>;
 
   return_car: ({
@@ -50,6 +75,8 @@ export interface IRentACarContract extends IBaseContractClient {
   }) => Promise<this>;
 
   remove_car: ({ owner }: { owner: string }) => Promise<this>;
+  get_car_token: ({ owner }: { owner: string }) => Promise<number>;
+  owner_of: ({ token_id }: { token_id: number }) => Promise<string>;
 
   payout_owner: ({
     owner,
@@ -0,0 +1,11 @@
+export interface IQuote {
+  start: number;
+  end: number;
+  list_rent: bigint;
+  base_rent: bigint;
+  commission: bigint;
+  deposit: bigint;
+  discount: bigint;
+  tax: bigint;
+  total: bigint;
+}
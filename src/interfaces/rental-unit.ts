@@ -0,0 +1,4 @@
+export enum RentalUnit {
+  HOUR = "Hour",
+  DAY = "Day",
+}
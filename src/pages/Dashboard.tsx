@@ -12,6 +12,7 @@ import { useStellarAccounts } from "../providers/StellarAccountProvider.tsx";
 import { stellarService } from "../services/stellar.service.ts";
 import { walletService } from "../services/wallet.service.ts";
 import { ONE_XLM_IN_STROOPS } from "../utils/xlm-in-stroops.ts";
+import { MAX_RENTAL_DAYS, MIN_RENTAL_DAYS } from "../utils/rental-limits.ts";
 import { Keypair } from "@stellar/stellar-sdk";
 
 export default function Dashboard() {
@@ -116,6 +117,8 @@ export default function Dashboard() {
         owner: ownerAddress,
         price_per_day: pricePerDay * ONE_XLM_IN_STROOPS,
         commission_amount: commissionAmount * ONE_XLM_IN_STROOPS, // Convert XLM to stroops
+        min_days: MIN_RENTAL_DAYS,
+        max_days: MAX_RENTAL_DAYS,
       });
       const xdr = addCarResult.toXDR();
       
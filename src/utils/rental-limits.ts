@@ -0,0 +1,3 @@
+// Defaults for new cars, owners can change them later with set_rental_limits
+export const MIN_RENTAL_DAYS = 1;
+export const MAX_RENTAL_DAYS = 30;